@@ -0,0 +1,30 @@
+/*
+ * Generate the C header for the interface in src/ffi.rs
+ *
+ * Only with the header feature, include/singlenum.h is kept in the repository
+ * and a plain build does not touch the source tree:
+ *
+ *   cargo build --features header
+ *
+ */
+#[cfg(feature = "header")]
+fn main() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let directory =
+        std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let config = cbindgen::Config::from_file(format!("{directory}/cbindgen.toml"))
+        .expect("Unable to read cbindgen.toml");
+    cbindgen::Builder::new()
+        .with_crate(&directory)
+        .with_config(config)
+        .generate()
+        .expect("Unable to generate C header")
+        .write_to_file(format!("{directory}/include/singlenum.h"));
+}
+
+#[cfg(not(feature = "header"))]
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,127 @@
+/* Generated by cbindgen from src/ffi.rs, do not edit */
+
+#ifndef SINGLENUM_H
+#define SINGLENUM_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ Outcome of solving, an error is negative.
+ */
+typedef enum SinglenumOutcome {
+  SinglenumOutcome_SOLVED = 0,
+  SinglenumOutcome_LIMITREACHED = 1,
+  SinglenumOutcome_TIMEDOUT = 2,
+  SinglenumOutcome_ERROR = -1,
+} SinglenumOutcome;
+
+/*
+ A puzzle being solved, owned by the caller until freed.
+ */
+typedef struct SinglenumTable SinglenumTable;
+
+/*
+ Stats of solving so far.
+ */
+typedef struct SinglenumStats {
+  int32_t iterations;
+  size_t snapshots;
+  size_t rollbacks;
+} SinglenumStats;
+
+/*
+ Next logical step: the square, from 0 top-left, to set to value.
+ */
+typedef struct SinglenumHint {
+  size_t square;
+  size_t line;
+  size_t column;
+  size_t value;
+  /*
+   0 line, 1 column or 2 box with one value left, 3 only one value
+   possible in the square, 4 only one square in the box for the value.
+   */
+  uint8_t technique;
+} SinglenumHint;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Create a classic table from 81 squares, 0 for an empty square.
+
+ `attempts` limits the steps of solving the table, over all `singlenum_solve`
+ calls, it can not be changed once the table is created.
+
+ Returns NULL when there are not 81 squares of 0-9, or the givens conflict.
+
+ # Safety
+
+ `squares` must point to `len` readable bytes.
+ */
+struct SinglenumTable *singlenum_table_new(const uint8_t *squares, size_t len, int32_t attempts);
+
+/*
+ Solve the table, for at most `timeout_ms` milliseconds, 0 for no limit.
+
+ The limit of attempts is the one given to `singlenum_table_new`.
+
+ # Safety
+
+ `table` must come from `singlenum_table_new` and not be freed.
+ */
+enum SinglenumOutcome singlenum_solve(struct SinglenumTable *table, uint64_t timeout_ms);
+
+/*
+ Copy the 81 squares to `out`, 0 for an empty square.
+
+ Returns the number of squares written, or -1 when `len` is less than 81.
+
+ # Safety
+
+ `table` must come from `singlenum_table_new` and not be freed, `out` must
+ point to `len` writable bytes.
+ */
+int32_t singlenum_grid(const struct SinglenumTable *table, uint8_t *out, size_t len);
+
+/*
+ Stats of solving so far, all 0 for a NULL table.
+
+ # Safety
+
+ `table` must come from `singlenum_table_new` and not be freed.
+ */
+struct SinglenumStats singlenum_stats(const struct SinglenumTable *table);
+
+/*
+ Next logical step, without changing the table.
+
+ Returns 1 with the step written to `out`, 0 when no logical step is left
+ or -1 on error.
+
+ # Safety
+
+ `table` must come from `singlenum_table_new` and not be freed, `out` must
+ point to a writable `SinglenumHint`.
+ */
+int32_t singlenum_hint(const struct SinglenumTable *table, struct SinglenumHint *out);
+
+/*
+ Free the table, NULL is ignored.
+
+ # Safety
+
+ `table` must come from `singlenum_table_new` and not be freed already.
+ */
+void singlenum_table_free(struct SinglenumTable *table);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SINGLENUM_H */
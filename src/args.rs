@@ -1,33 +1,258 @@
 use camino::Utf8PathBuf;
-use clap::{ArgAction, Args, Parser};
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use std::io::{stdout, IsTerminal};
+use std::time::Duration;
 
 #[derive(Debug, Parser, PartialEq)]
 #[command(name = "singlenum", author, version, about, long_about = None, arg_required_else_help = true)]
 pub struct Arguments {
+    #[command(subcommand)]
+    pub command: Command,
+
     /// Attempts before giving up
     #[arg(global = true, long, default_value_t = 500)]
     pub attempts: i32,
 
+    /// Color values by where they came from
+    #[arg(global = true, long, value_enum, default_value_t = Color::AUTO)]
+    pub color: Color,
+
+    /// Style of the boards drawn
+    #[arg(global = true, long, value_enum, default_value_t = Style::UNICODE)]
+    pub style: Style,
+
+    /// Time limit per puzzle, e.g. 500ms, 2s or 1m
+    #[arg(global = true, long, value_parser = duration)]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
+pub enum Color {
+    AUTO,
+    ALWAYS,
+    NEVER,
+}
+
+impl Color {
+    /*
+     * Use color, auto only when output is a terminal
+     *
+     */
+    pub fn enabled(&self) -> bool {
+        match self {
+            Color::AUTO => stdout().is_terminal(),
+            Color::ALWAYS => true,
+            Color::NEVER => false,
+        }
+    }
+}
+
+/*
+ * Parse a duration, in seconds unless it ends with ms, s or m
+ *
+ */
+pub fn duration(value: &str) -> Result<Duration, String> {
+    let (number, unit) = match value {
+        x if x.ends_with("ms") => (&x[..x.len() - 2], 0.001),
+        x if x.ends_with('s') => (&x[..x.len() - 1], 1.0),
+        x if x.ends_with('m') => (&x[..x.len() - 1], 60.0),
+        x => (x, 1.0),
+    };
+    match number
+        .parse::<f64>()
+        .map(|x| Duration::try_from_secs_f64(x * unit))
+    {
+        Ok(Ok(duration)) => Ok(duration),
+        _ => Err(format!("invalid duration: {value:?}")),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
+pub enum Style {
+    UNICODE,
+    ASCII,
+    COMPACT,
+    ACCESSIBLE,
+}
+
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
+pub enum Render {
+    SVG,
+}
+
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
+pub enum Format {
+    JSON,
+    LINE,
+}
+
+/*
+ * How boards are drawn, shared by solve and draw
+ *
+ */
+#[derive(Debug, Args, PartialEq)]
+pub struct Board {
+    /// Draw the candidates of each square
+    #[arg(long, action=ArgAction::SetTrue)]
+    pub candidates: bool,
+
+    /// Render start and end state to files next to the puzzle
+    #[arg(long, value_enum)]
+    pub render: Option<Render>,
+}
+
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
+pub enum Output {
+    BOARD,
+    NDJSON,
+    LINE,
+}
+
+#[derive(Debug, Args, PartialEq)]
+pub struct Solve {
+    /// Puzzle file, path containing puzzles, or - for stdin
+    pub path: Utf8PathBuf,
+
+    /// Result per puzzle, boards or one line each [default: board, ndjson for stdin]
+    #[arg(long, value_enum)]
+    pub output: Option<Output>,
+
+    #[command(flatten)]
+    pub board: Board,
+
     /// Visual boards (start and end)
-    #[arg(global = true, long, action=ArgAction::SetFalse)]
+    #[arg(long, action=ArgAction::SetFalse)]
     pub novisual: bool,
 
-    /// Just draw puzzle, don't solve
+    /// Redraw the board in place after every step while solving
     #[arg(long, action=ArgAction::SetTrue)]
-    pub just_draw: bool,
+    pub animate: bool,
+
+    /// Delay between steps when animating, in milliseconds
+    #[arg(long, default_value_t = 100)]
+    pub delay: u64,
 
-    #[clap(flatten)]
-    pub group: RequiredOption,
+    /// Record progress to file, skip and resume from it on a rerun
+    #[arg(long)]
+    pub checkpoint: Option<Utf8PathBuf>,
 }
 
-#[derive(Debug, Args, PartialEq)]
-#[group(required = true, multiple = false)]
-pub struct RequiredOption {
-    /// File containing puzzle
-    #[arg(short, long = "file")]
-    pub file: Option<Utf8PathBuf>,
-
-    ///// Path containing puzzles
-    #[arg(short, long = "path")]
-    pub path: Option<Utf8PathBuf>,
+#[derive(Debug, Subcommand, PartialEq)]
+pub enum Command {
+    /// Solve puzzles
+    Solve(Solve),
+
+    /// Draw puzzles, without solving
+    Draw {
+        /// Puzzle file, or path containing puzzles
+        path: Utf8PathBuf,
+
+        #[command(flatten)]
+        board: Board,
+    },
+
+    /// Check that puzzles load and their givens do not conflict
+    Validate {
+        /// Puzzle file, or path containing puzzles
+        path: Utf8PathBuf,
+
+        /// Completed grid to verify as the solution of the puzzle file
+        #[arg(long)]
+        solution: Option<Utf8PathBuf>,
+    },
+
+    /// Generate classic puzzles that solve without guessing
+    Generate {
+        /// Givens to leave, more are left when no more can be removed
+        #[arg(long, default_value_t = 30)]
+        givens: usize,
+
+        /// Puzzles to generate, one per line
+        #[arg(long, default_value_t = 1)]
+        count: usize,
+    },
+
+    /// Grade the difficulty of puzzles by solving them
+    Grade {
+        /// Puzzle file, or path containing puzzles
+        path: Utf8PathBuf,
+    },
+
+    /// Measure how the solver does, and compare with a baseline
+    Bench {
+        /// Puzzle file, or path containing puzzles
+        path: Utf8PathBuf,
+
+        /// Runs of each puzzle, the median is taken
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+
+        /// Baseline to compare with, exits with an error on a regression.
+        /// Written by this run when it does not exist
+        #[arg(long)]
+        baseline: Option<Utf8PathBuf>,
+
+        /// Write the results as a baseline
+        #[arg(long)]
+        save: Option<Utf8PathBuf>,
+
+        /// Percent worse than the baseline that is a regression
+        #[arg(long, default_value_t = 20.0)]
+        threshold: f64,
+    },
+
+    /// Convert a classic puzzle between a JSON list and a line of digits
+    Convert {
+        /// File containing puzzle
+        file: Utf8PathBuf,
+
+        /// Format to convert to
+        #[arg(long, value_enum)]
+        to: Format,
+    },
+
+    /// Shell completions, to stdout
+    Completions {
+        /// Shell to complete for
+        shell: Shell,
+    },
+
+    /// Serve the solver as a JSON API over HTTP
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        bind: String,
+
+        /// Requests handled at the same time
+        #[arg(long, default_value_t = 4)]
+        threads: usize,
+
+        /// Largest request body, in bytes
+        #[arg(long, default_value_t = 65536)]
+        max_body: usize,
+    },
+
+    /// Answer JSON-RPC requests, one per line, on stdin and stdout
+    Worker,
+
+    /// Play puzzle in the terminal
+    Play {
+        /// File containing puzzle
+        file: Utf8PathBuf,
+    },
+
+    /// Printable booklet of puzzles, with solutions, as HTML
+    Booklet {
+        /// Path containing puzzles
+        path: Utf8PathBuf,
+
+        /// Puzzles per page
+        #[arg(long, default_value_t = 4)]
+        per_page: usize,
+
+        /// Output file
+        #[arg(short, long, default_value = "booklet.html")]
+        output: Utf8PathBuf,
+    },
 }
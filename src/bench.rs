@@ -0,0 +1,195 @@
+use crate::cancel::CancelToken;
+use crate::components::table::core::Table;
+use crate::enums::Progress;
+use anyhow::Result as AnyhowResult;
+use camino::Utf8PathBuf;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/*
+ * Time differences smaller than this are noise, not regressions
+ *
+ */
+const SLACK_MS: f64 = 1.0;
+
+/*
+ * How a puzzle did over several runs, the median of each
+ *
+ * Guesses are random, so iterations, snapshots and rollbacks vary from run to
+ * run as well as the time.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measure {
+    pub puzzle: String,
+    pub runs: usize,
+    pub solved: usize,
+    pub median_ms: f64,
+    pub iterations: i32,
+    pub snapshots: usize,
+    pub rollbacks: usize,
+}
+
+impl fmt::Display for Measure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {:.2} ms, solved {}/{} [iterations: {}, snapshots: {}, rollbacks: {}]",
+            self.puzzle,
+            self.median_ms,
+            self.solved,
+            self.runs,
+            self.iterations,
+            self.snapshots,
+            self.rollbacks
+        )
+    }
+}
+
+/*
+ * Measures of a benchmark run, written to file to compare later runs with
+ *
+ */
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub measures: Vec<Measure>,
+}
+
+impl Baseline {
+    pub fn load(file: &Utf8PathBuf) -> AnyhowResult<Baseline> {
+        let content = std::fs::read_to_string(file)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, file: &Utf8PathBuf) -> AnyhowResult<()> {
+        std::fs::write(file, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn get_measure(&self, puzzle: &str) -> Option<&Measure> {
+        self.measures.iter().find(|x| x.puzzle == puzzle)
+    }
+}
+
+/*
+ * A puzzle that got worse than its baseline
+ *
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub puzzle: String,
+    pub metric: String,
+    pub baseline: f64,
+    pub current: f64,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} {:.2} -> {:.2}",
+            self.puzzle, self.metric, self.baseline, self.current
+        )?;
+        if self.baseline > 0.0 {
+            let change = (self.current - self.baseline) / self.baseline * 100.0;
+            write!(f, " ({change:+.0}%)")?;
+        }
+        Ok(())
+    }
+}
+
+/*
+ * Solve a copy of the table runs times
+ *
+ * A run that takes longer than the timeout is stopped and counts as not
+ * solved.
+ *
+ */
+pub fn measure(
+    puzzle: &str,
+    table: &Table,
+    runs: usize,
+    timeout: Option<Duration>,
+) -> AnyhowResult<Measure> {
+    let runs = runs.max(1);
+    let mut solved = 0;
+    let mut times: Vec<f64> = Vec::new();
+    let mut iterations: Vec<i32> = Vec::new();
+    let mut snapshots: Vec<usize> = Vec::new();
+    let mut rollbacks: Vec<usize> = Vec::new();
+
+    for _ in 0..runs {
+        let mut table = table.clone();
+        let token = match timeout {
+            Some(timeout) => CancelToken::new().with_timeout(timeout),
+            None => CancelToken::new(),
+        };
+        let start = Instant::now();
+        let progress = table.solve_with(&token)?;
+        times.push(start.elapsed().as_secs_f64() * 1000.0);
+
+        if let Progress::Solved(_) = progress {
+            solved += 1;
+        }
+        iterations.push(table.get_iterations());
+        snapshots.push(table.get_snapshots_taken());
+        rollbacks.push(table.get_snapshot_rollbacks());
+    }
+
+    Ok(Measure {
+        puzzle: String::from(puzzle),
+        runs,
+        solved,
+        median_ms: median(times),
+        iterations: median(iterations),
+        snapshots: median(snapshots),
+        rollbacks: median(rollbacks),
+    })
+}
+
+fn median<T: PartialOrd + Copy>(mut values: Vec<T>) -> T {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    values[values.len() / 2]
+}
+
+/*
+ * Regressions from the baseline, beyond threshold percent
+ *
+ * The median time is compared, and the share of runs solved. Iterations,
+ * snapshots and rollbacks are only reported, they follow the random guesses
+ * too closely to compare. Puzzles that are not in the baseline are new, not
+ * compared.
+ *
+ */
+pub fn compare(baseline: &Baseline, current: &Baseline, threshold: f64) -> Vec<Regression> {
+    let limit = 1.0 + threshold / 100.0;
+    let mut regressions: Vec<Regression> = Vec::new();
+    for measure in &current.measures {
+        let Some(base) = baseline.get_measure(&measure.puzzle) else {
+            continue;
+        };
+        let mut regression = |metric: &str, baseline: f64, current: f64| {
+            regressions.push(Regression {
+                puzzle: measure.puzzle.clone(),
+                metric: String::from(metric),
+                baseline,
+                current,
+            })
+        };
+
+        let (solved, base_solved) = (
+            measure.solved as f64 / measure.runs as f64,
+            base.solved as f64 / base.runs as f64,
+        );
+        if solved * limit < base_solved {
+            regression("solved", base_solved, solved);
+        }
+        if measure.median_ms > base.median_ms * limit
+            && measure.median_ms - base.median_ms > SLACK_MS
+        {
+            regression("median_ms", base.median_ms, measure.median_ms);
+        }
+    }
+    regressions
+}
@@ -0,0 +1,141 @@
+use crate::cancel::CancelToken;
+use crate::components::table::core::Table;
+use crate::components::table::svg::render_svg;
+use crate::enums::Progress;
+use anyhow::Result as AnyhowResult;
+use std::fmt::Write;
+use std::time::Duration;
+
+/*
+ * Time to solve each puzzle when no timeout is given
+ *
+ */
+pub const TIMEOUT: Duration = Duration::from_secs(10);
+
+const STYLE: &str = "
+@page { size: A4; margin: 15mm; }
+body { font-family: sans-serif; margin: 0; }
+h1 { text-align: center; }
+.page { display: grid; gap: 10mm; break-after: page; page-break-after: always; }
+.page:last-child { break-after: auto; page-break-after: auto; }
+.puzzle { break-inside: avoid; text-align: center; }
+.puzzle h2 { font-size: 14pt; margin: 0 0 2mm 0; }
+.puzzle .difficulty { color: #5e5c64; font-size: 10pt; margin-bottom: 2mm; }
+.puzzle svg { width: 100%; height: auto; }
+.puzzle .unsolved { color: #5e5c64; font-style: italic; }
+.solutions .puzzle h2 { font-size: 10pt; }
+";
+
+/*
+ * Difficulty of a puzzle, by how the solver got along
+ *
+ * Solved by the engine alone is easy, a few guesses medium and more than
+ * that hard. Not solved within the attempts is unsolved.
+ *
+ */
+pub fn difficulty(table: &Table, solved: bool) -> &'static str {
+    match (solved, table.get_snapshots_taken()) {
+        (false, _) => "Unsolved",
+        (true, 0) => "Easy",
+        (true, 1..=3) => "Medium",
+        (true, _) => "Hard",
+    }
+}
+
+/*
+ * Printable booklet of puzzles as a self-contained HTML file
+ *
+ * Puzzles are given with their titles and laid out per_page to a page,
+ * numbered in order. Each puzzle is solved for its difficulty, stopping after
+ * timeout, and the solutions are added as an appendix, several more to a
+ * page. A puzzle the solver could not solve is listed there without a grid,
+ * its difficulty is "Timed out" when it ran out of time and "No solution" when
+ * the solver found its givens have none.
+ *
+ */
+pub fn booklet(
+    puzzles: Vec<(String, Table)>,
+    per_page: usize,
+    timeout: Duration,
+) -> AnyhowResult<String> {
+    let per_page = per_page.max(1);
+    let mut entries: Vec<(String, String, String, Option<String>)> = Vec::new();
+    for (title, table) in puzzles {
+        let mut solution = table.clone();
+        let token = CancelToken::new().with_timeout(timeout);
+        let (difficulty, solved) = match solution.solve_with(&token) {
+            Ok(Progress::Solved(_)) => (difficulty(&solution, true), true),
+            Ok(Progress::TimedOut(_)) => ("Timed out", false),
+            Ok(_) => (difficulty(&solution, false), false),
+            Err(e) => {
+                log::debug!("[booklet] {title}: {e}");
+                ("No solution", false)
+            }
+        };
+        entries.push((
+            escape(&title),
+            difficulty.to_string(),
+            render_svg(&table, false),
+            solved.then(|| render_svg(&solution, false)),
+        ));
+    }
+
+    let mut html = String::new();
+    let _ = writeln!(html, "<!DOCTYPE html>");
+    let _ = writeln!(html, "<html>\n<head>\n<meta charset=\"utf-8\">");
+    let _ = writeln!(html, "<title>Puzzles</title>\n<style>{STYLE}</style>");
+    let _ = writeln!(html, "</head>\n<body>");
+
+    let columns = if per_page == 1 { 1 } else { 2 };
+    for (page, chunk) in entries.chunks(per_page).enumerate() {
+        let _ = writeln!(
+            html,
+            "<section class=\"page\" style=\"grid-template-columns: repeat({columns}, 1fr);\">"
+        );
+        for (i, (title, difficulty, puzzle, _)) in chunk.iter().enumerate() {
+            let _ = writeln!(
+                html,
+                "<div class=\"puzzle\"><h2>{}. {title}</h2><div class=\"difficulty\">{difficulty}</div>\n{puzzle}</div>",
+                page * per_page + i + 1
+            );
+        }
+        let _ = writeln!(html, "</section>");
+    }
+
+    // Solutions, four times as many to a page
+    let solutions_per_page = per_page * 4;
+    for (page, chunk) in entries.chunks(solutions_per_page).enumerate() {
+        let _ = writeln!(
+            html,
+            "<section class=\"page solutions\" style=\"grid-template-columns: repeat({}, 1fr);\">",
+            2 * columns
+        );
+        if page == 0 {
+            let _ = writeln!(html, "<h1 style=\"grid-column: 1 / -1;\">Solutions</h1>");
+        }
+        for (i, (title, _, _, solution)) in chunk.iter().enumerate() {
+            let solution = solution
+                .as_deref()
+                .unwrap_or("<div class=\"unsolved\">No solution found</div>");
+            let _ = writeln!(
+                html,
+                "<div class=\"puzzle\"><h2>{}. {title}</h2>\n{solution}</div>",
+                page * solutions_per_page + i + 1
+            );
+        }
+        let _ = writeln!(html, "</section>");
+    }
+
+    let _ = writeln!(html, "</body>\n</html>");
+    Ok(html)
+}
+
+/*
+ * Escape text for HTML
+ */
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
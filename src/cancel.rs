@@ -0,0 +1,58 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/*
+ * Token to stop solving, checked between steps
+ *
+ * Clones share the flag, so cancelling one cancels all of them, e.g. from
+ * another thread or a signal handler. A deadline is kept per token.
+ *
+ */
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /*
+     * Token on an existing flag, e.g. one registered for SIGINT
+     *
+     */
+    pub fn from_flag(flag: Arc<AtomicBool>) -> CancelToken {
+        CancelToken {
+            cancelled: flag,
+            deadline: None,
+        }
+    }
+
+    /*
+     * Token sharing the flag that also times out, from now
+     *
+     * A timeout too long to be a point in time never times out.
+     *
+     */
+    pub fn with_timeout(&self, timeout: Duration) -> CancelToken {
+        CancelToken {
+            cancelled: Arc::clone(&self.cancelled),
+            deadline: Instant::now().checked_add(timeout),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn timed_out(&self) -> bool {
+        self.deadline.is_some_and(|x| Instant::now() >= x)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::components::table::core::Table;
+use anyhow::Result as AnyhowResult;
+use camino::Utf8PathBuf;
+use serde::{Deserialize, Serialize};
+
+/*
+ * A puzzle that has been run, with the result as printed
+ *
+ * Whether it matched its known solution is None when it has none.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Done {
+    pub puzzle: String,
+    pub solved: bool,
+    #[serde(default)]
+    pub matches: Option<bool>,
+    pub message: String,
+}
+
+/*
+ * The puzzle being solved when the checkpoint was written
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Current {
+    pub puzzle: String,
+    pub table: Table,
+}
+
+/*
+ * Progress of a run over puzzles, written to file
+ *
+ * Puzzles done are skipped on a rerun, the current puzzle is resumed from
+ * its table as it was.
+ *
+ */
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub done: Vec<Done>,
+    pub current: Option<Current>,
+}
+
+impl Checkpoint {
+    /*
+     * Read a checkpoint, a missing file is a new checkpoint
+     *
+     */
+    pub fn load(file: &Utf8PathBuf) -> AnyhowResult<Checkpoint> {
+        if !file.exists() {
+            return Ok(Checkpoint::default());
+        }
+        let content = std::fs::read_to_string(file)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /*
+     * Write the checkpoint
+     *
+     * Written to a temporary file first, then renamed over the old one, so an
+     * interrupted write leaves the last checkpoint.
+     *
+     */
+    pub fn save(&self, file: &Utf8PathBuf) -> AnyhowResult<()> {
+        let temporary = Utf8PathBuf::from(format!("{file}.tmp"));
+        std::fs::write(&temporary, serde_json::to_string(self)?)?;
+        std::fs::rename(&temporary, file)?;
+        Ok(())
+    }
+
+    pub fn get_done(&self, puzzle: &str) -> Option<&Done> {
+        self.done.iter().find(|x| x.puzzle == puzzle)
+    }
+
+    /*
+     * Take the table of the puzzle, if it was the one being solved
+     *
+     */
+    pub fn resume(&mut self, puzzle: &str) -> Option<Table> {
+        match &self.current {
+            Some(current) if current.puzzle == puzzle => self.current.take().map(|x| x.table),
+            _ => None,
+        }
+    }
+
+    pub fn finish(&mut self, puzzle: &str, solved: bool, matches: Option<bool>, message: &str) {
+        self.current = None;
+        self.done.retain(|x| x.puzzle != puzzle);
+        self.done.push(Done {
+            puzzle: String::from(puzzle),
+            solved,
+            matches,
+            message: String::from(message),
+        });
+    }
+}
@@ -1,6 +1,7 @@
 use crate::utils::helpers::remove_element;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ABox {
     pub _id: usize,
     pub _taken: Vec<usize>,
@@ -0,0 +1,110 @@
+use crate::utils::helpers::{max_candidate, min_candidate, prune_sum};
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use serde::{Deserialize, Serialize};
+
+/*
+ * Killer cage
+ *
+ * Values in the cage do not repeat and sum to the given sum.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Cage {
+    pub sum: usize,
+    pub _squares: Vec<usize>,
+}
+
+impl Cage {
+    pub fn new(sum: usize, square_ids: Vec<usize>) -> AnyhowResult<Cage> {
+        if square_ids.is_empty() || square_ids.len() > 9 {
+            return Err(anyhow!("Cage must have 1 to 9 squares: {square_ids:?}"));
+        }
+        if square_ids.iter().any(|x| *x >= 81) {
+            return Err(anyhow!("Cage has squares outside table: {square_ids:?}"));
+        }
+        if (1..square_ids.len()).any(|i| square_ids[..i].contains(&square_ids[i])) {
+            return Err(anyhow!("Cage has duplicate squares: {square_ids:?}"));
+        }
+
+        Ok(Cage {
+            sum,
+            _squares: square_ids,
+        })
+    }
+
+    pub fn get_square_ids(&self) -> Vec<usize> {
+        self._squares.clone()
+    }
+
+    /*
+     * Prune candidates
+     *
+     * Candidates are given per square of the cage, in order. A set square
+     * has its value as only candidate. A candidate is kept only if the other
+     * squares can hold distinct values that, together with it, reach the sum.
+     *
+     */
+    pub fn prune(&self, candidates: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        let candidates = prune_sum(self.sum, candidates);
+
+        (0..candidates.len())
+            .map(|i| {
+                candidates[i]
+                    .iter()
+                    .copied()
+                    .filter(|x| {
+                        let others: Vec<&Vec<usize>> = candidates
+                            .iter()
+                            .enumerate()
+                            .filter(|(j, _)| *j != i)
+                            .map(|(_, c)| c)
+                            .collect();
+                        self.sum >= *x && reachable(self.sum - x, &others, &[*x])
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /*
+     * Check that set values do not break the cage
+     *
+     * Values are given per square of the cage, in order. A value of 0 is a
+     * square not yet set.
+     *
+     */
+    pub fn valid(&self, values: &[usize]) -> bool {
+        let set: Vec<usize> = values.iter().copied().filter(|x| *x != 0).collect();
+        if (1..set.len()).any(|i| set[..i].contains(&set[i])) {
+            return false;
+        }
+
+        let sum: usize = set.iter().sum();
+        let unset = values.len() - set.len();
+        if unset == 0 {
+            sum == self.sum
+        } else {
+            sum + unset <= self.sum
+        }
+    }
+}
+
+/*
+ * Can the squares hold distinct values, not already used, that reach the sum
+ *
+ */
+fn reachable(sum: usize, candidates: &[&Vec<usize>], used: &[usize]) -> bool {
+    let Some((first, rest)) = candidates.split_first() else {
+        return sum == 0;
+    };
+
+    let low: usize = rest.iter().map(|x| min_candidate(x)).sum();
+    let high: usize = rest.iter().map(|x| max_candidate(x)).sum();
+    first.iter().any(|x| {
+        !used.contains(x)
+            && sum >= x + low
+            && sum <= x + high
+            && reachable(sum - x, rest, &[used, &[*x]].concat())
+    })
+}
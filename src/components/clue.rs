@@ -0,0 +1,270 @@
+use crate::enums::{ClueKind, Side};
+use crate::utils::helpers::{max_candidate, min_candidate, prune_sum};
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+impl FromStr for ClueKind {
+    type Err = anyhow::Error;
+
+    fn from_str(kind: &str) -> AnyhowResult<ClueKind> {
+        match kind {
+            "sandwich" => Ok(ClueKind::SANDWICH),
+            "little-killer" => Ok(ClueKind::LITTLEKILLER),
+            _ => Err(anyhow!("Unsupported clue kind: {kind:?}")),
+        }
+    }
+}
+
+impl fmt::Display for ClueKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClueKind::SANDWICH => write!(f, "sandwich"),
+            ClueKind::LITTLEKILLER => write!(f, "little-killer"),
+        }
+    }
+}
+
+/*
+ * Clue outside the table
+ *
+ *  - sandwich: sum of the squares between 1 and 9 on a line or column
+ *  - little-killer: sum of the squares on a diagonal, digits may repeat
+ *
+ * The side and index tell where the clue is drawn. Index is the column for
+ * clues on top or bottom, and the line for clues on the left or right.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Clue {
+    pub kind: ClueKind,
+    pub sum: usize,
+    pub side: Side,
+    pub index: usize,
+    pub direction: (i32, i32),
+    pub _squares: Vec<usize>,
+}
+
+impl Clue {
+    /*
+     * Sandwich clue to the left of a line
+     *
+     */
+    pub fn sandwich_line(line: usize, sum: usize) -> AnyhowResult<Clue> {
+        if line >= 9 {
+            return Err(anyhow!("Sandwich clue on line outside table: {line:?}"));
+        }
+        Ok(Clue {
+            kind: ClueKind::SANDWICH,
+            sum,
+            side: Side::LEFT,
+            index: line,
+            direction: (0, 1),
+            _squares: (0..9).map(|x| line * 9 + x).collect(),
+        })
+    }
+
+    /*
+     * Sandwich clue above a column
+     *
+     */
+    pub fn sandwich_column(column: usize, sum: usize) -> AnyhowResult<Clue> {
+        if column >= 9 {
+            return Err(anyhow!("Sandwich clue on column outside table: {column:?}"));
+        }
+        Ok(Clue {
+            kind: ClueKind::SANDWICH,
+            sum,
+            side: Side::TOP,
+            index: column,
+            direction: (1, 0),
+            _squares: (0..9).map(|x| x * 9 + column).collect(),
+        })
+    }
+
+    /*
+     * Little killer clue
+     *
+     * The diagonal starts at a square on the edge of the table and goes in
+     * direction: "down-right", "down-left", "up-right" or "up-left". The clue
+     * is drawn outside the edge the diagonal starts from.
+     *
+     */
+    pub fn little_killer(square_id: usize, direction: &str, sum: usize) -> AnyhowResult<Clue> {
+        let (l, c): (i32, i32) = match direction {
+            "down-right" => (1, 1),
+            "down-left" => (1, -1),
+            "up-right" => (-1, 1),
+            "up-left" => (-1, -1),
+            _ => return Err(anyhow!("Unsupported direction: {direction:?}")),
+        };
+        if square_id >= 81 {
+            return Err(anyhow!("Little killer outside table: {square_id:?}"));
+        }
+
+        let line = square_id / 9;
+        let column = square_id % 9;
+        let (side, index) = if l == 1 && line == 0 {
+            (Side::TOP, column)
+        } else if l == -1 && line == 8 {
+            (Side::BOTTOM, column)
+        } else if c == 1 && column == 0 {
+            (Side::LEFT, line)
+        } else if c == -1 && column == 8 {
+            (Side::RIGHT, line)
+        } else {
+            return Err(anyhow!(
+                "Little killer must start on the edge: {square_id:?} {direction:?}"
+            ));
+        };
+
+        let mut squares: Vec<usize> = Vec::new();
+        let (mut line, mut column) = (line as i32, column as i32);
+        while (0..9).contains(&line) && (0..9).contains(&column) {
+            squares.push((line * 9 + column) as usize);
+            line += l;
+            column += c;
+        }
+
+        Ok(Clue {
+            kind: ClueKind::LITTLEKILLER,
+            sum,
+            side,
+            index,
+            direction: (l, c),
+            _squares: squares,
+        })
+    }
+
+    pub fn get_square_ids(&self) -> Vec<usize> {
+        self._squares.clone()
+    }
+
+    /*
+     * Text drawn outside the table
+     *
+     */
+    pub fn label(&self) -> String {
+        match (&self.kind, self.direction) {
+            (ClueKind::LITTLEKILLER, (1, 1)) => format!("{}↘", self.sum),
+            (ClueKind::LITTLEKILLER, (1, -1)) => format!("{}↙", self.sum),
+            (ClueKind::LITTLEKILLER, (-1, 1)) => format!("{}↗", self.sum),
+            (ClueKind::LITTLEKILLER, (-1, -1)) => format!("{}↖", self.sum),
+            _ => format!("{}", self.sum),
+        }
+    }
+
+    /*
+     * Prune candidates
+     *
+     * Candidates are given per square of the clue, in order. A set square
+     * has its value as only candidate. Returns the candidates that can still
+     * satisfy the clue.
+     *
+     */
+    pub fn prune(&self, candidates: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        match self.kind {
+            ClueKind::SANDWICH => prune_sandwich(self.sum, candidates),
+            ClueKind::LITTLEKILLER => prune_sum(self.sum, candidates),
+        }
+    }
+
+    /*
+     * Check that set values do not break the clue
+     *
+     * Values are given per square of the clue, in order. A value of 0 is a
+     * square not yet set.
+     *
+     */
+    pub fn valid(&self, values: &[usize]) -> bool {
+        match self.kind {
+            ClueKind::SANDWICH => {
+                let one = values.iter().position(|x| *x == 1);
+                let nine = values.iter().position(|x| *x == 9);
+                if let (Some(one), Some(nine)) = (one, nine) {
+                    let between = &values[one.min(nine) + 1..one.max(nine)];
+                    let sum: usize = between.iter().sum();
+                    let unset = between.iter().filter(|x| **x == 0).count();
+                    if unset == 0 {
+                        return sum == self.sum;
+                    }
+                    return sum + 2 * unset <= self.sum;
+                }
+                true
+            }
+            ClueKind::LITTLEKILLER => {
+                let sum: usize = values.iter().sum();
+                let unset = values.iter().filter(|x| **x == 0).count();
+                if unset == 0 {
+                    sum == self.sum
+                } else {
+                    sum + unset <= self.sum
+                }
+            }
+        }
+    }
+}
+
+/*
+ * Try every placement of 1 and 9 that the candidates allow. For a placement
+ * where the squares in between can reach the sum, keep the values that
+ * support it. Anything not supported by any placement is removed.
+ */
+fn prune_sandwich(sum: usize, candidates: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = candidates.len();
+    let mut result: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+    for one in (0..n).filter(|x| candidates[*x].contains(&1)) {
+        for nine in (0..n).filter(|x| *x != one && candidates[*x].contains(&9)) {
+            let (first, last) = (one.min(nine), one.max(nine));
+            let inner: Vec<Vec<usize>> = candidates[first + 1..last]
+                .iter()
+                .map(|c| c.iter().copied().filter(|x| *x != 1 && *x != 9).collect())
+                .collect();
+            if inner.iter().any(|x: &Vec<usize>| x.is_empty()) {
+                continue;
+            }
+
+            let low: usize = inner.iter().map(|x| min_candidate(x)).sum();
+            let high: usize = inner.iter().map(|x| max_candidate(x)).sum();
+            if sum < low || sum > high {
+                continue;
+            }
+
+            let pruned = prune_sum(sum, &inner);
+            if pruned.iter().any(|x| x.is_empty()) {
+                continue;
+            }
+
+            for i in 0..n {
+                let supported: Vec<usize> = if i == one {
+                    vec![1]
+                } else if i == nine {
+                    vec![9]
+                } else if first < i && i < last {
+                    pruned[i - first - 1].clone()
+                } else {
+                    candidates[i]
+                        .iter()
+                        .copied()
+                        .filter(|x| *x != 1 && *x != 9)
+                        .collect()
+                };
+                for value in supported {
+                    if !result[i].contains(&value) {
+                        result[i].push(value);
+                    }
+                }
+            }
+        }
+    }
+
+    // Keep the order of the candidates
+    candidates
+        .iter()
+        .zip(result)
+        .map(|(c, r)| c.iter().copied().filter(|x| r.contains(x)).collect())
+        .collect()
+}
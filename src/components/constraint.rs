@@ -0,0 +1,37 @@
+use crate::components::cage::Cage;
+use crate::components::clue::Clue;
+use crate::components::pair::Pair;
+use crate::components::path::Path;
+use crate::enums::{PairKind, Rule};
+
+/*
+ * Constraint on top of classic sudoku
+ *
+ * A puzzle file lists its variant rules, each is parsed into one of these
+ * and added to the table with Table::add_constraint.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Constraint {
+    Rule(Rule),
+    Region(Vec<usize>),
+    Cage(Cage),
+    Pair(Pair),
+    Negative(PairKind),
+    Path(Path),
+    Clue(Clue),
+}
+
+/*
+ * Squares on a diagonal
+ *
+ * The main diagonal goes from top-left to bottom-right, the anti diagonal
+ * from top-right to bottom-left.
+ *
+ */
+pub fn diagonal(anti: bool) -> Vec<usize> {
+    match anti {
+        false => (0..9).map(|x| x * 10).collect(),
+        true => (0..9).map(|x| x * 8 + 8).collect(),
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/*
+ * One 9x9 grid of a table
+ *
+ * A classic puzzle has a single grid, while Samurai and other overlapping
+ * puzzles have several. The grid is placed at (line, column) on a larger
+ * canvas, and squares holds the ids of its 81 squares from top-left to
+ * bottom-right. Squares where grids overlap are shared.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Grid {
+    pub _id: usize,
+    pub line: usize,
+    pub column: usize,
+    pub _squares: Vec<usize>,
+}
+
+impl Grid {
+    pub fn new(id: usize, line: usize, column: usize, square_ids: Vec<usize>) -> Grid {
+        Grid {
+            _id: id,
+            line,
+            column,
+            _squares: square_ids,
+        }
+    }
+
+    pub fn get_square_ids(&self) -> Vec<usize> {
+        self._squares.clone()
+    }
+
+    /*
+     * Get square id at line and column within the grid
+     *
+     */
+    pub fn get_square_id(&self, line: usize, column: usize) -> usize {
+        self._squares[line * 9 + column]
+    }
+}
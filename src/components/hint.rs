@@ -0,0 +1,69 @@
+use crate::enums::{Container, HintLevel, Technique};
+use std::fmt;
+
+impl fmt::Display for Container {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Container::ABOX => write!(f, "box"),
+            Container::LINE => write!(f, "line"),
+            Container::COLUMN => write!(f, "column"),
+            Container::SQUARE => write!(f, "square"),
+        }
+    }
+}
+
+impl fmt::Display for Technique {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Technique::LINEONELEFT => write!(f, "only one value is left in the line"),
+            Technique::COLUMNONELEFT => write!(f, "only one value is left in the column"),
+            Technique::BOXONELEFT => write!(f, "only one value is left in the box"),
+            Technique::ONLYONEPOSSIBLE => write!(f, "only one value is possible in a square"),
+            Technique::BOX => write!(f, "a value is possible in only one square of the box"),
+        }
+    }
+}
+
+/*
+ * Next logical step
+ *
+ * Found by one of the engine routines, the square is where the value would
+ * be set. Lines, columns and boxes are numbered from 1 when described. When
+ * the square alone tells the value, the square is the container, by id, and
+ * described by its line and column.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hint {
+    pub container: Container,
+    pub container_id: usize,
+    pub technique: Technique,
+    pub square_id: usize,
+    pub line: usize,
+    pub column: usize,
+    pub value: usize,
+}
+
+impl Hint {
+    /*
+     * Describe the hint with more detail for each level
+     *
+     */
+    pub fn describe(&self, level: &HintLevel) -> String {
+        let container = match self.container {
+            Container::SQUARE => format!("line {}, column {}", self.line + 1, self.column + 1),
+            _ => format!("{} {}", self.container, self.container_id + 1),
+        };
+        match level {
+            HintLevel::CONTAINER => format!("Look at {container}"),
+            HintLevel::TECHNIQUE => format!("Look at {container}, {}", self.technique),
+            HintLevel::ANSWER => format!(
+                "Line {}, column {} is {}, {}",
+                self.line + 1,
+                self.column + 1,
+                self.value,
+                self.technique
+            ),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::enums::PairKind;
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+impl FromStr for PairKind {
+    type Err = anyhow::Error;
+
+    fn from_str(kind: &str) -> AnyhowResult<PairKind> {
+        match kind {
+            "white" => Ok(PairKind::WHITE),
+            "black" => Ok(PairKind::BLACK),
+            "x" => Ok(PairKind::X),
+            "v" => Ok(PairKind::V),
+            _ => Err(anyhow!("Unsupported pair kind: {kind:?}")),
+        }
+    }
+}
+
+impl fmt::Display for PairKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PairKind::WHITE => write!(f, "white"),
+            PairKind::BLACK => write!(f, "black"),
+            PairKind::X => write!(f, "x"),
+            PairKind::V => write!(f, "v"),
+        }
+    }
+}
+
+impl PairKind {
+    /*
+     * Check if two values satisfy the relation
+     *
+     *  - white: consecutive values
+     *  - black: one value is double the other
+     *  - x: values sum to 10
+     *  - v: values sum to 5
+     *
+     */
+    pub fn holds(&self, a: usize, b: usize) -> bool {
+        match self {
+            PairKind::WHITE => a.abs_diff(b) == 1,
+            PairKind::BLACK => a == 2 * b || b == 2 * a,
+            PairKind::X => a + b == 10,
+            PairKind::V => a + b == 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Pair {
+    pub kind: PairKind,
+    pub _0: usize,
+    pub _1: usize,
+}
+
+impl Pair {
+    pub fn new(kind: PairKind, square_ids: Vec<usize>) -> AnyhowResult<Pair> {
+        if square_ids.len() != 2 {
+            return Err(anyhow!("Pair must have two squares: {square_ids:?}"));
+        }
+
+        let (a, b) = (
+            square_ids[0].min(square_ids[1]),
+            square_ids[0].max(square_ids[1]),
+        );
+        if b >= 81 || !adjacent(a, b) {
+            return Err(anyhow!("Pair squares are not adjacent: {square_ids:?}"));
+        }
+
+        Ok(Pair { kind, _0: a, _1: b })
+    }
+
+    pub fn get_square_ids(&self) -> Vec<usize> {
+        vec![self._0, self._1]
+    }
+
+    /*
+     * Check if pair is between the two squares, in any order
+     *
+     */
+    pub fn between(&self, a: usize, b: usize) -> bool {
+        (self._0 == a && self._1 == b) || (self._0 == b && self._1 == a)
+    }
+}
+
+/*
+ * Check if two squares share an edge
+ *
+ */
+pub fn adjacent(a: usize, b: usize) -> bool {
+    let (a, b) = (a.min(b), a.max(b));
+    (b - a == 1 && a / 9 == b / 9) || b - a == 9
+}
@@ -0,0 +1,217 @@
+use crate::enums::PathKind;
+use crate::utils::helpers::{max_candidate, min_candidate};
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+impl FromStr for PathKind {
+    type Err = anyhow::Error;
+
+    fn from_str(kind: &str) -> AnyhowResult<PathKind> {
+        match kind {
+            "thermo" => Ok(PathKind::THERMO),
+            "arrow" => Ok(PathKind::ARROW),
+            "palindrome" => Ok(PathKind::PALINDROME),
+            _ => Err(anyhow!("Unsupported line kind: {kind:?}")),
+        }
+    }
+}
+
+impl fmt::Display for PathKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PathKind::THERMO => write!(f, "thermo"),
+            PathKind::ARROW => write!(f, "arrow"),
+            PathKind::PALINDROME => write!(f, "palindrome"),
+        }
+    }
+}
+
+/*
+ * Line constraint
+ *
+ * Named Path to not be confused with Line, which is a row of the table. The
+ * squares are ordered, and the meaning depends on the kind:
+ *
+ *  - thermo: values strictly increase from the bulb (first square)
+ *  - arrow: the circle (first square) is the sum of the rest
+ *  - palindrome: values read the same in both directions
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Path {
+    pub kind: PathKind,
+    pub _squares: Vec<usize>,
+}
+
+impl Path {
+    pub fn new(kind: PathKind, square_ids: Vec<usize>) -> AnyhowResult<Path> {
+        if square_ids.len() < 2 {
+            return Err(anyhow!(
+                "Line must have at least two squares: {square_ids:?}"
+            ));
+        }
+        if square_ids.iter().any(|x| *x >= 81) {
+            return Err(anyhow!("Line has squares outside table: {square_ids:?}"));
+        }
+
+        Ok(Path {
+            kind,
+            _squares: square_ids,
+        })
+    }
+
+    pub fn get_square_ids(&self) -> Vec<usize> {
+        self._squares.clone()
+    }
+
+    /*
+     * Prune candidates
+     *
+     * Candidates are given per square on the path, in path order. A set
+     * square has its value as only candidate. Returns the candidates that
+     * can still satisfy the path.
+     *
+     */
+    pub fn prune(&self, candidates: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        match self.kind {
+            PathKind::THERMO => prune_thermo(candidates),
+            PathKind::ARROW => prune_arrow(candidates),
+            PathKind::PALINDROME => prune_palindrome(candidates),
+        }
+    }
+
+    /*
+     * Check that set values do not break the path
+     *
+     * Values are given per square on the path, in path order. A value of 0
+     * is a square not yet set.
+     *
+     */
+    pub fn valid(&self, values: &[usize]) -> bool {
+        match self.kind {
+            PathKind::THERMO => {
+                let set: Vec<(usize, usize)> = values
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, x)| **x != 0)
+                    .map(|(i, x)| (i, *x))
+                    .collect();
+                // Squares in between need room for a value each
+                set.windows(2).all(|w| w[0].1 + (w[1].0 - w[0].0) <= w[1].1)
+            }
+            PathKind::ARROW => {
+                let circle = values[0];
+                let arrow = &values[1..];
+                let sum: usize = arrow.iter().sum();
+                let unset = arrow.iter().filter(|x| **x == 0).count();
+                if circle == 0 {
+                    sum + unset <= 9
+                } else if unset == 0 {
+                    sum == circle
+                } else {
+                    sum + unset <= circle
+                }
+            }
+            PathKind::PALINDROME => {
+                let n = values.len();
+                (0..n / 2).all(|i| {
+                    values[i] == 0 || values[n - 1 - i] == 0 || values[i] == values[n - 1 - i]
+                })
+            }
+        }
+    }
+}
+
+/*
+ * Each square must be larger than the smallest possible value before it,
+ * and smaller than the largest possible value after it.
+ */
+fn prune_thermo(candidates: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = candidates.len();
+    let mut low: Vec<usize> = vec![1; n];
+    let mut high: Vec<usize> = vec![9; n];
+
+    for i in 0..n {
+        low[i] = min_candidate(&candidates[i]);
+        if i > 0 {
+            low[i] = low[i].max(low[i - 1] + 1);
+        }
+    }
+    for i in (0..n).rev() {
+        high[i] = max_candidate(&candidates[i]);
+        if i < n - 1 {
+            high[i] = high[i].min(high[i + 1].saturating_sub(1));
+        }
+    }
+
+    candidates
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            c.iter()
+                .copied()
+                .filter(|x| low[i] <= *x && *x <= high[i])
+                .collect()
+        })
+        .collect()
+}
+
+/*
+ * The circle must be within the smallest and largest possible sum of the
+ * arrow. Each square on the arrow must leave room for the rest of the arrow
+ * to reach one of the circle values.
+ */
+fn prune_arrow(candidates: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let circle = &candidates[0];
+    let arrow = &candidates[1..];
+    let low: usize = arrow.iter().map(|x| min_candidate(x)).sum();
+    let high: usize = arrow.iter().map(|x| max_candidate(x)).sum();
+
+    let mut result: Vec<Vec<usize>> = Vec::new();
+    let circle_pruned: Vec<usize> = circle
+        .iter()
+        .copied()
+        .filter(|x| low <= *x && *x <= high)
+        .collect();
+
+    for c in arrow.iter() {
+        let others_low = low - min_candidate(c);
+        let others_high = high - max_candidate(c);
+        result.push(
+            c.iter()
+                .copied()
+                .filter(|x| {
+                    circle_pruned
+                        .iter()
+                        .any(|s| *s >= x + others_low && *s <= x + others_high)
+                })
+                .collect(),
+        );
+    }
+
+    result.insert(0, circle_pruned);
+    result
+}
+
+/*
+ * Mirrored squares must share the same value
+ */
+fn prune_palindrome(candidates: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = candidates.len();
+    let mut result: Vec<Vec<usize>> = candidates.to_vec();
+
+    for i in 0..n / 2 {
+        let j = n - 1 - i;
+        let common: Vec<usize> = candidates[i]
+            .iter()
+            .copied()
+            .filter(|x| candidates[j].contains(x))
+            .collect();
+        result[i] = common.clone();
+        result[j] = common;
+    }
+    result
+}
@@ -0,0 +1,48 @@
+use crate::utils::helpers::remove_element;
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use serde::{Deserialize, Serialize};
+
+/*
+ * Extra region
+ *
+ * Nine squares that must hold all values 1-9, just like Line, Column and
+ * ABox. Used for diagonals and extra regions (Windoku and the like).
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Region {
+    pub _id: usize,
+    pub _taken: Vec<usize>,
+    pub _remaining: Vec<usize>,
+    pub _squares: Vec<usize>,
+}
+
+impl Region {
+    pub fn new(id: usize, square_ids: Vec<usize>) -> AnyhowResult<Region> {
+        if square_ids.len() != 9 || square_ids.iter().any(|x| *x >= 81) {
+            return Err(anyhow!("Region must have 9 squares: {square_ids:?}"));
+        }
+        if (1..square_ids.len()).any(|i| square_ids[..i].contains(&square_ids[i])) {
+            return Err(anyhow!("Region has duplicate squares: {square_ids:?}"));
+        }
+
+        Ok(Region {
+            _id: id,
+            _taken: Vec::new(),
+            _remaining: Vec::new(),
+            _squares: square_ids,
+        })
+    }
+
+    pub fn get_square_ids(&self) -> Vec<usize> {
+        self._squares.clone()
+    }
+
+    pub fn set_taken(&mut self, value: usize) {
+        self._taken.push(value);
+
+        // Remove value from _remaining
+        remove_element(value, &mut self._remaining);
+    }
+}
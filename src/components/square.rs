@@ -1,9 +1,10 @@
 use crate::enums::SetKind;
 use log;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Square {
     pub id: usize,
     pub value: usize,
@@ -12,9 +13,10 @@ pub struct Square {
     pub column_potentials: Vec<usize>,
     pub box_potentials: Vec<usize>,
     pub abox_id: usize,
-    pub line_id: usize,
-    pub column_id: usize,
+    pub line_ids: Vec<usize>,
+    pub column_ids: Vec<usize>,
     pub history: Vec<usize>,
+    pub kind: Option<SetKind>,
 }
 
 impl Hash for Square {
@@ -39,8 +41,8 @@ impl Square {
             "[set_value] ID: {:?}, value: {:?}, | {:?} {:?} potentials {:?}",
             self.id,
             value,
-            self.line_id,
-            self.column_id,
+            self.line_ids,
+            self.column_ids,
             self.potentials,
         );
         self.value = value;
@@ -52,6 +54,7 @@ impl Square {
         if kind == SetKind::GUESS {
             self.history.push(value);
         }
+        self.kind = Some(kind);
     }
 
     /*
@@ -0,0 +1,146 @@
+use crate::cancel::CancelToken;
+use crate::components::table::core::Table;
+use crate::components::table::draw::board;
+use crate::components::table::render::Renderer;
+use crate::enums::{Progress, Step};
+use anyhow::Result as AnyhowResult;
+use std::thread::sleep;
+use std::time::Duration;
+
+/*
+ * Solve the puzzle, redrawing the board in place after each step
+ *
+ * The square just set is highlighted, with the technique that set it or as a
+ * guess, and the potentials the variant rules removed with it. On a rollback
+ * the squares that were reverted are highlighted on the board as it was
+ * before the guess. Each frame is shown for delay milliseconds. The token is
+ * checked between steps.
+ *
+ * Boards are drawn by the renderer. The squares are only highlighted with
+ * highlight, in color on the box drawing board of a single grid, otherwise
+ * the message below the board tells what changed. Redrawing in place moves
+ * the cursor, so only animate on a terminal.
+ */
+pub fn animate(
+    table: &mut Table,
+    delay: u64,
+    renderer: &dyn Renderer,
+    highlight: bool,
+    token: &CancelToken,
+) -> AnyhowResult<Progress> {
+    print!("\x1b[2J");
+    loop {
+        let before: Vec<usize> = table.squares.iter().map(|x| x.value).collect();
+        let step = table.step_with(token)?;
+        let after: Vec<usize> = table.squares.iter().map(|x| x.value).collect();
+
+        let set: Vec<usize> = (0..after.len())
+            .filter(|x| before[*x] == 0 && after[*x] != 0)
+            .collect();
+        let reverted: Vec<usize> = (0..after.len())
+            .filter(|x| before[*x] != 0 && after[*x] == 0)
+            .collect();
+
+        let (squares, message) = match step {
+            Step::Done(progress) => return Ok(progress),
+            Step::Deduced(technique) => {
+                let message = format!("{}, {technique}", placed(table, &set));
+                (set, message)
+            }
+            Step::Guessed => {
+                let message = format!("{}, guess", placed(table, &set));
+                (set, message)
+            }
+            Step::RolledBack => {
+                let message = format!("Rollback, {} square(s) reverted", reverted.len());
+                frame(
+                    table, &before, &reverted, &message, "\x1b[41m", renderer, highlight,
+                );
+                sleep(Duration::from_millis(delay));
+                continue;
+            }
+        };
+
+        let mut message = vec![message];
+        message.extend(table.get_eliminations().iter().map(|x| x.to_string()));
+
+        let values: Vec<usize> = table.squares.iter().map(|x| x.value).collect();
+        let message = message.join("\n");
+        frame(
+            table, &values, &squares, &message, "\x1b[7m", renderer, highlight,
+        );
+        sleep(Duration::from_millis(delay));
+    }
+}
+
+/*
+ * Squares just set, as "Line l, column c is v"
+ */
+fn placed(table: &Table, set: &[usize]) -> String {
+    let placed: Vec<String> = set
+        .iter()
+        .map(|x| &table.squares[*x])
+        .map(|x| {
+            format!(
+                "Line {}, column {} is {}",
+                x.line_ids[0] % 9 + 1,
+                x.column_ids[0] % 9 + 1,
+                x.value
+            )
+        })
+        .collect();
+    placed.join(", ")
+}
+
+/*
+ * Draw one frame from the top of the screen
+ *
+ * The board is drawn with the given values. With highlight, a single grid is
+ * drawn as a box drawing board with the squares in style, otherwise the
+ * renderer draws it without.
+ */
+fn frame(
+    table: &Table,
+    values: &[usize],
+    squares: &[usize],
+    message: &str,
+    style: &str,
+    renderer: &dyn Renderer,
+    highlight: bool,
+) {
+    print!("\x1b[H");
+    if !highlight || table.get_grids().len() > 1 {
+        let mut shown = table.clone();
+        for (square, value) in shown.squares.iter_mut().zip(values) {
+            square.value = *value;
+        }
+        for line in renderer.render(&shown).lines() {
+            println!("{line}\x1b[K");
+        }
+        message_lines(message);
+        return;
+    }
+
+    for (i, row) in board(table, values, false).iter().enumerate() {
+        let mut chars: Vec<String> = row.chars().map(|x| x.to_string()).collect();
+        if i % 2 == 1 {
+            for id in squares.iter().filter(|x| **x / 9 == i / 2) {
+                let c = 4 * (id % 9) + 1;
+                chars[c] = format!("{style}{}", chars[c]);
+                chars[c + 2] = format!("{}\x1b[0m", chars[c + 2]);
+            }
+        }
+        println!("{}", chars.concat());
+    }
+    message_lines(message);
+}
+
+/*
+ * Print the message below the board, clearing what was there before
+ */
+fn message_lines(message: &str) {
+    for line in message.lines() {
+        println!("\x1b[K{line}");
+    }
+    print!("\x1b[J");
+}
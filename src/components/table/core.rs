@@ -1,20 +1,33 @@
+use crate::cancel::CancelToken;
 use crate::components::abox::ABox;
+use crate::components::cage::Cage;
+use crate::components::clue::Clue;
 use crate::components::column::Column;
+use crate::components::constraint::Constraint;
+use crate::components::grid::Grid;
+use crate::components::hint::Hint;
 use crate::components::line::Line;
+use crate::components::pair::{self, Pair};
+use crate::components::path::Path;
+use crate::components::region::Region;
 use crate::components::square::Square;
-use crate::enums::{Container, Progress, SetKind};
+use crate::components::table::rules::{self, Elimination};
+use crate::enums::{Container, PairKind, Progress, Rule, SetKind, Step, Technique};
 use crate::utils::helpers;
+use crate::verify::{Place, Unit, Violation};
 use anyhow::anyhow;
 use anyhow::Result as AnyhowResult;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SnapShot {
     square: Vec<Square>,
     line: Vec<Line>,
     column: Vec<Column>,
     abox: Vec<ABox>,
+    region: Vec<Region>,
     value: usize,
     square_id: usize,
 }
@@ -25,22 +38,35 @@ impl Hash for SnapShot {
         self.line.hash(state);
         self.column.hash(state);
         self.abox.hash(state);
+        self.region.hash(state);
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Table {
     abox: Vec<ABox>,
     line: Vec<Line>,
     column: Vec<Column>,
+    region: Vec<Region>,
     pub squares: Vec<Square>,
+    grids: Vec<Grid>,
     snapshots: Vec<SnapShot>,
     max_attempts: i32,
     iteration: i32,
     snapshots_taken: usize,
     snapshot_rollbacks: usize,
+    rules: Vec<Rule>,
+    pairs: Vec<Pair>,
+    negative: Vec<PairKind>,
+    paths: Vec<Path>,
+    clues: Vec<Clue>,
+    cages: Vec<Cage>,
+    #[serde(default)]
+    eliminations: Vec<Elimination>,
 }
 
+type Routine = fn(&mut Table) -> AnyhowResult<bool>;
+
 impl Hash for Table {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.squares.hash(state);
@@ -48,92 +74,282 @@ impl Hash for Table {
 }
 
 impl Table {
-    pub fn new(configuration: Vec<usize>, max_attempts: i32) -> Table {
+    /*
+     * Create a table of a single grid of 81 squares
+     *
+     */
+    pub fn new(configuration: Vec<usize>, max_attempts: i32) -> AnyhowResult<Table> {
+        Table::new_multi([((0, 0), configuration)].to_vec(), max_attempts)
+    }
+
+    /*
+     * Create a table of one or more grids
+     *
+     * Each grid is given by its offset (line, column) on a larger canvas and
+     * its configuration of 81 squares, 0 to 9. Grids may overlap, as in Samurai, as
+     * long as they overlap on whole boxes. A square in the overlap belongs to
+     * one box, but to a line and a column in each of the grids.
+     *
+     * Square ids are given from top-left to bottom-right of the canvas, so a
+     * single grid has the same ids as in the drawing in main.rs.
+     *
+     */
+    pub fn new_multi(
+        grids: Vec<((usize, usize), Vec<usize>)>,
+        max_attempts: i32,
+    ) -> AnyhowResult<Table> {
+        let mut positions: Vec<(usize, usize)> = Vec::new();
+        for ((line, column), configuration) in &grids {
+            if line % 3 != 0 || column % 3 != 0 {
+                return Err(anyhow!(
+                    "Grid offset must be on a box boundary: {:?}",
+                    (line, column)
+                ));
+            }
+            if configuration.len() != 81 {
+                return Err(anyhow!(
+                    "Number of squares in configuration input is: {} != 81",
+                    configuration.len()
+                ));
+            }
+            if let Some(index) = configuration.iter().position(|x| *x > 9) {
+                return Err(anyhow!(
+                    "Square {index} of grid {:?} is {}, squares are 0 to 9",
+                    (line, column),
+                    configuration[index]
+                ));
+            }
+            for index in 0..81 {
+                positions.push((line + index / 9, column + index % 9));
+            }
+        }
+        positions.sort();
+        positions.dedup();
+
+        let square_id = |line: usize, column: usize| -> usize {
+            positions.binary_search(&(line, column)).unwrap()
+        };
+
+        let mut values: Vec<usize> = vec![0; positions.len()];
+        let mut line_ids: Vec<Vec<usize>> = vec![Vec::new(); positions.len()];
+        let mut column_ids: Vec<Vec<usize>> = vec![Vec::new(); positions.len()];
+        let mut abox_ids: Vec<usize> = vec![0; positions.len()];
+
         let mut a: Vec<ABox> = Vec::new();
-        let mut a_id: usize;
+        let mut a_origins: Vec<(usize, usize)> = Vec::new();
         let mut l: Vec<Line> = Vec::new();
         let mut c: Vec<Column> = Vec::new();
-        let mut s: Vec<Square> = Vec::new();
-
-        for (index, value) in configuration.iter().enumerate() {
-            a_id = match index {
-                0 | 1 | 2 | 9 | 10 | 11 | 18 | 19 | 20 => 0,
-                3 | 4 | 5 | 12 | 13 | 14 | 21 | 22 | 23 => 1,
-                6 | 7 | 8 | 15 | 16 | 17 | 24 | 25 | 26 => 2,
-                27 | 28 | 29 | 36 | 37 | 38 | 45 | 46 | 47 => 3,
-                30 | 31 | 32 | 39 | 40 | 41 | 48 | 49 | 50 => 4,
-                33 | 34 | 35 | 42 | 43 | 44 | 51 | 52 | 53 => 5,
-                54 | 55 | 56 | 63 | 64 | 65 | 72 | 73 | 74 => 6,
-                57 | 58 | 59 | 66 | 67 | 68 | 75 | 76 | 77 => 7,
-                60 | 61 | 62 | 69 | 70 | 71 | 78 | 79 | 80 => 8,
-                _ => panic!(
-                    "Number of squares in configuration input is: {} > 81",
-                    configuration.len()
-                ),
-            };
+        let mut g: Vec<Grid> = Vec::new();
 
-            let t = Square {
-                id: index,
-                value: *value,
-                potentials: Vec::new(),
-                line_potentials: Vec::new(),
-                column_potentials: Vec::new(),
-                box_potentials: Vec::new(),
-                abox_id: a_id,
-                line_id: index / 9,
-                column_id: index % 9,
-                history: Vec::new(),
-            };
-
-            s.push(t);
-            let numbers: Vec<usize> = (0..=8).collect();
+        for ((line, column), configuration) in &grids {
+            let ids: Vec<usize> = (0..81)
+                .map(|x| square_id(line + x / 9, column + x % 9))
+                .collect();
 
-            match index {
-                8 | 17 | 26 | 35 | 44 | 53 | 62 | 71 | 80 => l.push(Line::new(
-                    index / 9,
-                    numbers.iter().map(|x| index - x).collect(),
-                )),
-                _ => (),
+            for (index, value) in configuration.iter().enumerate() {
+                let id = ids[index];
+                if *value == 0 {
+                    continue;
+                }
+                if values[id] != 0 && values[id] != *value {
+                    return Err(anyhow!(
+                        "Overlapping grids disagree on square: {:?}",
+                        (line + index / 9, column + index % 9)
+                    ));
+                }
+                values[id] = *value;
             }
 
-            if let 72..=80 = index {
-                c.push(Column::new(
-                    index % 9,
-                    numbers.iter().map(|x| index - (9 * x)).collect(),
-                ));
-            }
+            for n in 0..9 {
+                let line_id = l.len();
+                let squares: Vec<usize> = (0..9).map(|x| ids[n * 9 + x]).collect();
+                for id in &squares {
+                    line_ids[*id].push(line_id);
+                }
+                l.push(Line::new(line_id, squares));
 
-            match index {
-                20 | 23 | 26 | 47 | 50 | 53 | 74 | 77 | 80 => a.push(ABox::new(
-                    a_id,
-                    [
-                        index - 9 - 9 - 2,
-                        index - 9 - 9 - 1,
-                        index - 9 - 9,
-                        index - 9 - 2,
-                        index - 9 - 1,
-                        index - 9,
-                        index - 2,
-                        index - 1,
-                        index,
-                    ]
-                    .to_vec(),
-                )),
-                _ => (),
+                let column_id = c.len();
+                let squares: Vec<usize> = (0..9).map(|x| ids[x * 9 + n]).collect();
+                for id in &squares {
+                    column_ids[*id].push(column_id);
+                }
+                c.push(Column::new(column_id, squares));
+
+                // Overlapping grids share boxes
+                let origin = (line + 3 * (n / 3), column + 3 * (n % 3));
+                if !a_origins.contains(&origin) {
+                    let a_id = a.len();
+                    let squares: Vec<usize> = (0..9)
+                        .map(|x| square_id(origin.0 + x / 3, origin.1 + x % 3))
+                        .collect();
+                    for id in &squares {
+                        abox_ids[*id] = a_id;
+                    }
+                    a.push(ABox::new(a_id, squares));
+                    a_origins.push(origin);
+                }
             }
+
+            g.push(Grid::new(g.len(), *line, *column, ids));
         }
 
-        Table {
+        let s: Vec<Square> = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| Square {
+                id: index,
+                value: *value,
+                potentials: Vec::new(),
+                line_potentials: Vec::new(),
+                column_potentials: Vec::new(),
+                box_potentials: Vec::new(),
+                abox_id: abox_ids[index],
+                line_ids: line_ids[index].clone(),
+                column_ids: column_ids[index].clone(),
+                history: Vec::new(),
+                kind: match value {
+                    0 => None,
+                    _ => Some(SetKind::GIVEN),
+                },
+            })
+            .collect();
+
+        Ok(Table {
             abox: a,
             line: l,
             column: c,
+            region: Vec::new(),
             squares: s,
+            grids: g,
             snapshots: Vec::new(),
             snapshots_taken: 0,
             max_attempts,
             iteration: 0,
             snapshot_rollbacks: 0,
+            rules: Vec::new(),
+            pairs: Vec::new(),
+            negative: Vec::new(),
+            paths: Vec::new(),
+            clues: Vec::new(),
+            cages: Vec::new(),
+            eliminations: Vec::new(),
+        })
+    }
+
+    /*
+     * Get the grids of the table
+     *
+     */
+    pub fn get_grids(&self) -> &Vec<Grid> {
+        &self.grids
+    }
+
+    /*
+     * Add a constraint on top of classic sudoku
+     *
+     * Constraints assume a single 9x9 grid, so they can not be added to a
+     * table of several grids.
+     *
+     */
+    pub fn add_constraint(&mut self, constraint: Constraint) -> AnyhowResult<&mut Self> {
+        if self.grids.len() > 1 {
+            return Err(anyhow!(
+                "Constraints are not supported on several grids: {constraint:?}"
+            ));
+        }
+
+        match constraint {
+            Constraint::Rule(rule) => self.rules.push(rule),
+            Constraint::Region(square_ids) => {
+                self.region
+                    .push(Region::new(self.region.len(), square_ids)?);
+            }
+            Constraint::Cage(cage) => self.cages.push(cage),
+            Constraint::Pair(pair) => self.pairs.push(pair),
+            Constraint::Negative(kind) => self.negative.push(kind),
+            Constraint::Path(path) => self.paths.push(path),
+            Constraint::Clue(clue) => self.clues.push(clue),
         }
+        Ok(self)
+    }
+
+    /*
+     * A single grid without variant rules
+     *
+     */
+    pub fn is_classic(&self) -> bool {
+        self.grids.len() == 1
+            && self.rules.is_empty()
+            && self.region.is_empty()
+            && self.pairs.is_empty()
+            && self.negative.is_empty()
+            && self.paths.is_empty()
+            && self.clues.is_empty()
+            && self.cages.is_empty()
+    }
+
+    /*
+     * Get the variant rules
+     *
+     */
+    pub fn get_rules(&self) -> &Vec<Rule> {
+        &self.rules
+    }
+
+    /*
+     * Get the pair kinds that hold only where marked
+     *
+     */
+    pub fn get_negative(&self) -> &Vec<PairKind> {
+        &self.negative
+    }
+
+    /*
+     * Get the clues outside the table
+     *
+     */
+    pub fn get_clues(&self) -> &Vec<Clue> {
+        &self.clues
+    }
+
+    /*
+     * Get the pair constraints
+     *
+     */
+    pub fn get_pairs(&self) -> &Vec<Pair> {
+        &self.pairs
+    }
+
+    /*
+     * Get the line constraints
+     *
+     */
+    pub fn get_paths(&self) -> &Vec<Path> {
+        &self.paths
+    }
+
+    /*
+     * Get the killer cages
+     *
+     */
+    pub fn get_cages(&self) -> &Vec<Cage> {
+        &self.cages
+    }
+
+    /*
+     * Get the extra regions
+     *
+     */
+    pub fn get_regions(&self) -> &Vec<Region> {
+        &self.region
+    }
+
+    /*
+     * Get the kind of pair between two squares, if any
+     *
+     */
+    pub fn get_pair_kind(&self, a: usize, b: usize) -> Option<&PairKind> {
+        self.pairs.iter().find(|x| x.between(a, b)).map(|x| &x.kind)
     }
 
     /*
@@ -146,13 +362,27 @@ impl Table {
      * history. When we do not guess it should not be needed.
      *
      */
-    fn set_square(&mut self, square_id: usize, value: usize, kind: SetKind) -> AnyhowResult<&Self> {
+    pub(crate) fn set_square(
+        &mut self,
+        square_id: usize,
+        value: usize,
+        kind: SetKind,
+    ) -> AnyhowResult<&Self> {
         self.squares[square_id].set_value(value, kind);
 
-        // Update Line, Column and ABox
-        self.line[self.squares[square_id].line_id].set_taken(value);
-        self.column[self.squares[square_id].column_id].set_taken(value);
+        // Update Lines, Columns and ABox, a shared square is on several
+        for line_id in self.squares[square_id].line_ids.clone() {
+            self.line[line_id].set_taken(value);
+        }
+        for column_id in self.squares[square_id].column_ids.clone() {
+            self.column[column_id].set_taken(value);
+        }
         self.abox[self.squares[square_id].abox_id].set_taken(value);
+        for region in &mut self.region {
+            if region._squares.contains(&square_id) {
+                region.set_taken(value);
+            }
+        }
 
         Ok(self)
     }
@@ -163,12 +393,9 @@ impl Table {
     pub fn complete(&mut self) -> Progress {
         self.iteration += 1;
         let progress: &usize = &self.squares.iter().filter(|x| x.value != 0).count();
-        let msg = format!(
-            "[iterations: {}, snapshots: {}, rollbacks: {}]",
-            self.iteration, self.snapshots_taken, self.snapshot_rollbacks
-        );
+        let msg = self.stats();
 
-        if *progress == 81 {
+        if *progress == self.squares.len() {
             Progress::Solved(msg)
         } else if self.iteration == self.max_attempts {
             Progress::LimitReached(msg)
@@ -177,6 +404,160 @@ impl Table {
         }
     }
 
+    /*
+     * Stats of solving so far, as given with the progress
+     *
+     */
+    fn stats(&self) -> String {
+        format!(
+            "[iterations: {}, snapshots: {}, rollbacks: {}]",
+            self.iteration, self.snapshots_taken, self.snapshot_rollbacks
+        )
+    }
+
+    /*
+     * Number of snapshots taken, that is guesses made, while solving
+     *
+     */
+    pub fn get_snapshots_taken(&self) -> usize {
+        self.snapshots_taken
+    }
+
+    pub fn get_snapshot_rollbacks(&self) -> usize {
+        self.snapshot_rollbacks
+    }
+
+    pub fn get_iterations(&self) -> i32 {
+        self.iteration
+    }
+
+    /*
+     * Solve the puzzle
+     *
+     * Step until the puzzle is solved or the limit of attempts is reached.
+     * Givens without a solution are an error, once every guess is undone.
+     *
+     */
+    pub fn solve(&mut self) -> AnyhowResult<Progress> {
+        self.solve_with(&CancelToken::new())
+    }
+
+    /*
+     * Solve the puzzle until the token is cancelled or times out
+     *
+     */
+    pub fn solve_with(&mut self, token: &CancelToken) -> AnyhowResult<Progress> {
+        loop {
+            if let Step::Done(progress) = self.step_with(token)? {
+                return Ok(progress);
+            }
+        }
+    }
+
+    /*
+     * One step of solving the puzzle, unless the token stops it
+     *
+     * A stopped puzzle is done, timed out or cancelled, with the table as it
+     * was after the last step.
+     *
+     */
+    pub fn step_with(&mut self, token: &CancelToken) -> AnyhowResult<Step> {
+        if token.timed_out() {
+            return Ok(Step::Done(Progress::TimedOut(self.stats())));
+        }
+        if token.is_cancelled() {
+            return Ok(Step::Done(Progress::Cancelled(self.stats())));
+        }
+        self.step()
+    }
+
+    /*
+     * One step of solving the puzzle
+     *
+     * Update and run the engine to set a square. When the engine can not, guess
+     * or roll back to the last snapshot. A deduction that fails validation is
+     * rolled back at once.
+     *
+     */
+    pub fn step(&mut self) -> AnyhowResult<Step> {
+        self.eliminations.clear();
+        match self.complete() {
+            Progress::InProgress(iteration) => log::debug!("[iteration] {iteration}"),
+            progress => return Ok(Step::Done(progress)),
+        };
+
+        // Update line, column, box, and finally squares. Then run Engine to set squares
+        self.update()?;
+
+        // An empty square with no value left can not be set, the last guess was wrong
+        if self
+            .squares
+            .iter()
+            .any(|x| x.value == 0 && x.potentials.is_empty())
+        {
+            self.snapshot_rollback()?;
+            return Ok(Step::RolledBack);
+        }
+
+        let before: Vec<usize> = self.squares.iter().map(|x| x.value).collect();
+        let rollbacks = self.snapshot_rollbacks;
+        if let Some(technique) = self.engine_step()? {
+            if self.snapshot_rollbacks > rollbacks {
+                return Ok(Step::RolledBack);
+            }
+            self.eliminations = self.rule_eliminations(&before);
+            return Ok(Step::Deduced(technique));
+        }
+
+        // Guess, first a qualified guess, then a somewhat less qualified (incompetent)
+        if self.qualified_guess()? || self.incompetent_guess()? {
+            self.eliminations = self.rule_eliminations(&before);
+            return Ok(Step::Guessed);
+        }
+        self.snapshot_rollback()?;
+        Ok(Step::RolledBack)
+    }
+
+    /*
+     * Get the potentials the variant rules removed in the last step
+     *
+     * These are the potentials of empty squares that see a square set in the
+     * step, a knight's or a king's move away, with its value. They are removed
+     * at the next update. Empty when the step did not set a square.
+     *
+     */
+    pub fn get_eliminations(&self) -> &Vec<Elimination> {
+        &self.eliminations
+    }
+
+    /*
+     * Potentials removed by the rules for the squares set since before
+     *
+     */
+    fn rule_eliminations(&self, before: &[usize]) -> Vec<Elimination> {
+        let mut eliminations: Vec<Elimination> = Vec::new();
+        for square in self
+            .squares
+            .iter()
+            .filter(|x| before[x.id] == 0 && x.value != 0)
+        {
+            for rule in &self.rules {
+                for neighbour_id in rules::neighbours(rule, square.id) {
+                    let neighbour = &self.squares[neighbour_id];
+                    if neighbour.value == 0 && neighbour.potentials.contains(&square.value) {
+                        eliminations.push(Elimination {
+                            rule: rule.clone(),
+                            square_id: neighbour_id,
+                            value: square.value,
+                            seen_by: square.id,
+                        });
+                    }
+                }
+            }
+        }
+        eliminations
+    }
+
     /*
      * Prepare a snapshot.
      *
@@ -187,6 +568,7 @@ impl Table {
             line: self.line.clone(),
             column: self.column.clone(),
             abox: self.abox.clone(),
+            region: self.region.clone(),
 
             // These values are invalid, they must be set by the 'guess' to the value of
             // the square we take a guess on.
@@ -214,19 +596,21 @@ impl Table {
      * After the rollback we need to update the square with the history of the
      * guess that did not lead anywhere. This shall prevent us from going that
      * route again.
+     *
+     * Without a snapshot there is no guess left to undo, the givens have no
+     * solution.
      */
     pub fn snapshot_rollback(&mut self) -> AnyhowResult<()> {
-        if self.snapshots.is_empty() {
-            panic!("ERROR, there is no snapshot!");
-        }
-
-        let snapshot = self.snapshots.pop().unwrap();
+        let Some(snapshot) = self.snapshots.pop() else {
+            return Err(anyhow!("No solution, no guess left to roll back"));
+        };
         log::debug!("[snapshot] Roll back to snapshot");
 
         self.squares = snapshot.square;
         self.line = snapshot.line;
         self.column = snapshot.column;
         self.abox = snapshot.abox;
+        self.region = snapshot.region;
 
         self.snapshot_rollbacks += 1;
 
@@ -240,22 +624,31 @@ impl Table {
     /*
      * Qualified Guess
      *
-     * This means that we take one square that have few hard potentials
-     * and set it to one of them, then we see how it goes ;)
+     * This means that we take the square with the fewest potentials not tried
+     * yet and set it to one of them, then we see how it goes ;)
+     *
+     * A square with all of its potentials tried is a dead end, there is no
+     * guess then and the step rolls back.
      */
     pub fn qualified_guess(&mut self) -> AnyhowResult<bool> {
         let mut snapshot = self.snapshot_prepare();
         let mut update: Option<(usize, usize)> = None;
-
-        'outer: for square in self.squares.iter() {
-            if !square.potentials.is_empty() {
-                for potential in &square.potentials {
-                    if square.history.contains(potential) {
-                        continue;
-                    }
-                    update = Some((square.id, *potential));
-                    break 'outer;
-                }
+        let mut fewest = usize::MAX;
+
+        for square in self.squares.iter().filter(|x| x.value == 0) {
+            let untried: Vec<usize> = square
+                .potentials
+                .iter()
+                .filter(|x| !square.history.contains(x))
+                .copied()
+                .collect();
+            if untried.is_empty() {
+                update = None;
+                break;
+            }
+            if untried.len() < fewest {
+                fewest = untried.len();
+                update = Some((square.id, untried[0]));
             }
         }
 
@@ -285,7 +678,7 @@ impl Table {
         let mut snapshot = self.snapshot_prepare();
         let mut update: Option<(usize, usize)> = None;
 
-        'outer: for square in self.squares.iter() {
+        'outer: for square in self.squares.iter().filter(|x| x.value == 0) {
             let potentials = helpers::multi_intersections(vec![
                 square.box_potentials.clone(),
                 square.line_potentials.clone(),
@@ -325,54 +718,92 @@ impl Table {
      * that we get an update on our data.
      */
     pub fn engine(&mut self) -> AnyhowResult<bool> {
-        let mut updated: bool;
+        Ok(self.engine_step()?.is_some())
+    }
 
-        updated = self.engine_line_one_left()?;
-        if updated {
-            let valid: bool = self.validate()?;
-            if !valid {
-                self.snapshot_rollback()?;
+    /*
+     * Run the engine, return the technique of the routine that set a square
+     *
+     */
+    pub fn engine_step(&mut self) -> AnyhowResult<Option<Technique>> {
+        for (technique, routine) in Table::routines() {
+            if routine(self)? {
+                let valid: bool = self.validate()?;
+                if !valid {
+                    self.snapshot_rollback()?;
+                }
+                return Ok(Some(technique));
             }
-            return Ok(true);
         }
+        Ok(None)
+    }
 
-        updated = self.engine_column_one_left()?;
-        if updated {
-            let valid: bool = self.validate()?;
-            if !valid {
-                self.snapshot_rollback()?;
-            }
-            return Ok(true);
-        }
+    /*
+     * Engine routines in the order they run, with their technique
+     *
+     */
+    fn routines() -> [(Technique, Routine); 5] {
+        [
+            (Technique::LINEONELEFT, Table::engine_line_one_left),
+            (Technique::COLUMNONELEFT, Table::engine_column_one_left),
+            (Technique::BOXONELEFT, Table::engine_box_one_left),
+            (Technique::ONLYONEPOSSIBLE, Table::engine_only_one_possible),
+            (Technique::BOX, Table::engine_box),
+        ]
+    }
 
-        updated = self.engine_box_one_left()?;
-        if updated {
-            let valid: bool = self.validate()?;
-            if !valid {
-                self.snapshot_rollback()?;
+    /*
+     * Next logical step, without changing the table
+     *
+     * The engine routines run, in the same order as in engine, on a copy of
+     * the table. The first one to set a square is the hint. Guesses are never
+     * used, so None means that there is no logical step left.
+     *
+     */
+    pub fn hint(&self) -> AnyhowResult<Option<Hint>> {
+        let mut table = self.clone();
+        table.update()?;
+        let before: Vec<usize> = table.squares.iter().map(|x| x.value).collect();
+
+        for (technique, routine) in Table::routines() {
+            if !routine(&mut table)? {
+                continue;
             }
-            return Ok(true);
-        }
+            let Some(square) = table.squares.iter().find(|x| x.value != before[x.id]) else {
+                continue;
+            };
 
-        updated = self.engine_only_one_possible()?;
-        if updated {
-            let valid: bool = self.validate()?;
-            if !valid {
-                self.snapshot_rollback()?;
-            }
-            return Ok(true);
-        }
+            // The line or column that got completed, a shared square is on several
+            let line_id = *square
+                .line_ids
+                .iter()
+                .find(|x| table.line[**x]._remaining.is_empty())
+                .unwrap_or(&square.line_ids[0]);
+            let column_id = *square
+                .column_ids
+                .iter()
+                .find(|x| table.column[**x]._remaining.is_empty())
+                .unwrap_or(&square.column_ids[0]);
+
+            let (container, container_id) = match technique {
+                Technique::LINEONELEFT => (Container::LINE, line_id),
+                Technique::COLUMNONELEFT => (Container::COLUMN, column_id),
+                Technique::ONLYONEPOSSIBLE => (Container::SQUARE, square.id),
+                _ => (Container::ABOX, square.abox_id),
+            };
 
-        updated = self.engine_box()?;
-        if updated {
-            let valid: bool = self.validate()?;
-            if !valid {
-                self.snapshot_rollback()?;
-            }
-            return Ok(true);
+            return Ok(Some(Hint {
+                container,
+                container_id,
+                technique,
+                square_id: square.id,
+                line: square.line_ids[0] % 9,
+                column: square.column_ids[0] % 9,
+                value: square.value,
+            }));
         }
 
-        Ok(false)
+        Ok(None)
     }
 
     /*
@@ -414,9 +845,16 @@ impl Table {
                     }
                 }
             }
+            Container::SQUARE => {
+                if self.squares[id].value == 0 {
+                    set_square_id = Some(id);
+                }
+            }
         }
 
-        self.set_square(set_square_id.unwrap(), value, SetKind::NORMAL)?;
+        let square_id = set_square_id
+            .ok_or_else(|| anyhow!("No empty square in {container} {id} for {value}"))?;
+        self.set_square(square_id, value, SetKind::NORMAL)?;
         Ok(())
     }
 
@@ -567,8 +1005,7 @@ impl Table {
             // Update
         }
 
-        if update.is_some() {
-            let (id, value) = update.unwrap();
+        if let Some((id, value)) = update {
             self.set_square(id, value, SetKind::NORMAL)?;
             log::debug!("[engine] engine_box -> true");
             return Ok(true);
@@ -583,7 +1020,7 @@ impl Table {
      *
      */
     fn get_abox(&self, _id: usize) -> AnyhowResult<&ABox> {
-        match self.abox.iter().filter(|x| x._id == _id).last() {
+        match self.abox.iter().rfind(|x| x._id == _id) {
             Some(abox) => Ok(abox),
             None => Err(anyhow!("Unable find abox with id: {_id}")),
         }
@@ -594,7 +1031,7 @@ impl Table {
      *
      */
     fn get_square(&self, _id: usize) -> AnyhowResult<&Square> {
-        match self.squares.iter().filter(|x| x.id == _id).last() {
+        match self.squares.iter().rfind(|x| x.id == _id) {
             Some(square) => Ok(square),
             None => Err(anyhow!("No square with id: {_id} found")),
         }
@@ -605,7 +1042,7 @@ impl Table {
      *
      */
     fn get_square_mut(&mut self, _id: usize) -> AnyhowResult<&mut Square> {
-        match self.squares.iter_mut().filter(|x| x.id == _id).last() {
+        match self.squares.iter_mut().rfind(|x| x.id == _id) {
             Some(square) => Ok(square),
             None => Err(anyhow!("No square with id: {_id} found")),
         }
@@ -622,7 +1059,12 @@ impl Table {
         self.update_line()?;
         self.update_column()?;
         self.update_abox()?;
+        self.update_region()?;
         self.update_square_potentials()?;
+        self.update_pair_potentials()?;
+        self.update_cage_potentials()?;
+        self.update_path_potentials()?;
+        self.update_clue_potentials()?;
         self.update_box_remove_potentials()?;
         Ok(self)
     }
@@ -632,11 +1074,19 @@ impl Table {
      *
      */
     fn update_square_potentials(&mut self) -> AnyhowResult<&mut Self> {
+        let values: Vec<usize> = self.squares.iter().map(|x| x.value).collect();
+
         for square in &mut self.squares {
-            let ln = self.line.get(square.line_id).unwrap()._taken.clone();
+            let mut ln: Vec<usize> = Vec::new();
+            for line_id in &square.line_ids {
+                ln.extend(&self.line.get(*line_id).unwrap()._taken);
+            }
             square.update("line_potentials", helpers::inverse_vec(&ln));
 
-            let cn = self.column.get(square.column_id).unwrap()._taken.clone();
+            let mut cn: Vec<usize> = Vec::new();
+            for column_id in &square.column_ids {
+                cn.extend(&self.column.get(*column_id).unwrap()._taken);
+            }
             square.update("column_potentials", helpers::inverse_vec(&cn));
 
             let bn = self.abox.get(square.abox_id).unwrap()._taken.clone();
@@ -648,12 +1098,222 @@ impl Table {
                     square.line_potentials.clone(),
                     square.column_potentials.clone(),
                 ]));
+
+                // Remove values taken in extra regions of the square
+                for region in self
+                    .region
+                    .iter()
+                    .filter(|x| x._squares.contains(&square.id))
+                {
+                    for value in &region._taken {
+                        if helpers::remove_element(*value, &mut square.potentials) {
+                            log::debug!(
+                                "[region] removed potential: {value:?} from square: {:?}, taken in region: {:?}",
+                                square.id,
+                                region._id
+                            );
+                        }
+                    }
+                }
+
+                // Remove values taken in the neighbourhood of the square
+                for rule in &self.rules {
+                    for neighbour_id in rules::neighbours(rule, square.id) {
+                        let value = values[neighbour_id];
+                        if value != 0 && helpers::remove_element(value, &mut square.potentials) {
+                            log::debug!(
+                                "[rule] {rule} removed potential: {value:?} from square: {:?}, seen by square: {neighbour_id:?}",
+                                square.id
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /*
+     * Remove potentials that can not satisfy pair constraints
+     *
+     * A potential is kept only if the other square of the pair has a value,
+     * or a potential, that satisfy the relation together with it. For the
+     * negative constraint, adjacent squares without a marker may not hold a
+     * value that satisfy the relation with an already set neighbour.
+     *
+     */
+    fn update_pair_potentials(&mut self) -> AnyhowResult<&mut Self> {
+        let mut removals: Vec<(usize, usize)> = Vec::new();
+
+        for pair in &self.pairs {
+            for (id, other_id) in [(pair._0, pair._1), (pair._1, pair._0)] {
+                let other = self.get_square(other_id)?;
+                let candidates: Vec<usize> = match other.value {
+                    0 => other.potentials.clone(),
+                    value => vec![value],
+                };
+
+                for potential in &self.get_square(id)?.potentials {
+                    if !candidates.iter().any(|x| pair.kind.holds(*potential, *x)) {
+                        removals.push((id, *potential));
+                    }
+                }
             }
         }
 
+        for kind in &self.negative {
+            for square in &self.squares {
+                for other_id in [square.id + 1, square.id + 9] {
+                    if other_id >= 81 || !pair::adjacent(square.id, other_id) {
+                        continue;
+                    }
+                    if self.get_pair_kind(square.id, other_id).is_some() {
+                        continue;
+                    }
+
+                    let other = self.get_square(other_id)?;
+                    for (a, b) in [(square, other), (other, square)] {
+                        if b.value == 0 {
+                            continue;
+                        }
+                        for potential in &a.potentials {
+                            if kind.holds(*potential, b.value) {
+                                removals.push((a.id, *potential));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        self.remove_potentials(removals, "pair")?;
+
+        Ok(self)
+    }
+
+    /*
+     * Remove potentials that can not satisfy killer cages
+     *
+     */
+    fn update_cage_potentials(&mut self) -> AnyhowResult<&mut Self> {
+        loop {
+            let mut removals: Vec<(usize, usize)> = Vec::new();
+
+            for cage in &self.cages {
+                let square_ids = cage.get_square_ids();
+                let pruned = cage.prune(&self.get_candidates(&square_ids)?);
+                removals.append(&mut self.get_unsupported(&square_ids, &pruned)?);
+            }
+
+            if removals.is_empty() {
+                break;
+            }
+            self.remove_potentials(removals, "cage")?;
+        }
+
+        Ok(self)
+    }
+
+    /*
+     * Remove potentials that can not satisfy line constraints
+     *
+     * Pruning one path may open up for pruning on another path that share
+     * squares with it, so keep going until nothing more can be removed.
+     *
+     */
+    fn update_path_potentials(&mut self) -> AnyhowResult<&mut Self> {
+        loop {
+            let mut removals: Vec<(usize, usize)> = Vec::new();
+
+            for path in &self.paths {
+                let square_ids = path.get_square_ids();
+                let pruned = path.prune(&self.get_candidates(&square_ids)?);
+                removals.append(&mut self.get_unsupported(&square_ids, &pruned)?);
+            }
+
+            if removals.is_empty() {
+                break;
+            }
+            self.remove_potentials(removals, "path")?;
+        }
+
+        Ok(self)
+    }
+
+    /*
+     * Remove potentials that can not satisfy clues outside the table
+     *
+     */
+    fn update_clue_potentials(&mut self) -> AnyhowResult<&mut Self> {
+        loop {
+            let mut removals: Vec<(usize, usize)> = Vec::new();
+
+            for clue in &self.clues {
+                let square_ids = clue.get_square_ids();
+                let pruned = clue.prune(&self.get_candidates(&square_ids)?);
+                removals.append(&mut self.get_unsupported(&square_ids, &pruned)?);
+            }
+
+            if removals.is_empty() {
+                break;
+            }
+            self.remove_potentials(removals, "clue")?;
+        }
+
         Ok(self)
     }
 
+    /*
+     * Get candidates for squares, the value if set otherwise the potentials
+     *
+     */
+    fn get_candidates(&self, square_ids: &[usize]) -> AnyhowResult<Vec<Vec<usize>>> {
+        let mut candidates: Vec<Vec<usize>> = Vec::new();
+        for square_id in square_ids {
+            let square = self.get_square(*square_id)?;
+            candidates.push(match square.value {
+                0 => square.potentials.clone(),
+                value => vec![value],
+            });
+        }
+        Ok(candidates)
+    }
+
+    /*
+     * Get (square id, potential) for potentials missing in pruned candidates
+     *
+     */
+    fn get_unsupported(
+        &self,
+        square_ids: &[usize],
+        pruned: &[Vec<usize>],
+    ) -> AnyhowResult<Vec<(usize, usize)>> {
+        let mut unsupported: Vec<(usize, usize)> = Vec::new();
+        for (square_id, pruned) in square_ids.iter().zip(pruned) {
+            for potential in &self.get_square(*square_id)?.potentials {
+                if !pruned.contains(potential) {
+                    unsupported.push((*square_id, *potential));
+                }
+            }
+        }
+        Ok(unsupported)
+    }
+
+    /*
+     * Remove (square id, potential)
+     *
+     */
+    fn remove_potentials(&mut self, removals: Vec<(usize, usize)>, tag: &str) -> AnyhowResult<()> {
+        for (id, potential) in removals {
+            let square = self.get_square_mut(id)?;
+            if helpers::remove_element(potential, &mut square.potentials) {
+                log::debug!("[{tag}] removed potential: {potential:?} from square: {id:?}");
+            }
+        }
+        Ok(())
+    }
+
     /*
      * Consider the case where two squares are the only ones in
      * the box that have the potential for x,y. In this case any
@@ -900,65 +1560,162 @@ impl Table {
         Ok(self)
     }
 
+    /*
+     * Update remaining and taken for each extra region
+     *
+     */
+    pub fn update_region(&mut self) -> AnyhowResult<&mut Self> {
+        let mut region_taken: HashMap<usize, Vec<usize>> = HashMap::new();
+
+        for region in &self.region {
+            let mut current_region: Vec<usize> = Vec::new();
+
+            // Populate all taken values for current region
+            for square_id in region.get_square_ids() {
+                let square = self.get_square(square_id)?;
+                if square.value != 0 {
+                    current_region.push(square.value)
+                }
+            }
+
+            region_taken.insert(region._id, current_region);
+        }
+
+        // Update all Regions
+        for region in &mut self.region {
+            let t = region_taken.remove(&region._id).unwrap();
+            region._remaining = helpers::inverse_vec(&t);
+            region._taken = t;
+        }
+
+        Ok(self)
+    }
+
     /*
      * Validate Table
      *
      * This is done by:
-     *  - line verification
-     *  - column verification
-     *  - box verification
+     *  - line, column, box and extra region verification
+     *  - rule verification
+     *  - pair verification
+     *  - line constraint verification
+     *  - clue verification
+     *  - cage verification
+     *
+     */
+    pub fn validate(&mut self) -> AnyhowResult<bool> {
+        Ok(self._validate_units(true)?.is_empty()
+            && self._validate_rules()?
+            && self._validate_pairs()?
+            && self._validate_paths()?
+            && self._validate_clues()?
+            && self._validate_cages()?)
+    }
+
+    /*
+     * Everything that does not hold, as for validate
      *
-     *  TODO: Should be possible to merge these methods into one
+     * Each value repeated in a line, column, box or extra region is a
+     * violation of its own. Variant constraints are a violation per kind,
+     * rules, pairs, lines, clues or cages, that does not hold.
      *
      */
-    fn validate(&mut self) -> AnyhowResult<bool> {
-        Ok(self._validate_line()? && self._validate_column()? && self._validate_box()?)
+    pub fn violations(&self) -> AnyhowResult<Vec<Violation>> {
+        let mut violations = self._validate_units(false)?;
+        let constraints: [(&str, bool); 5] = [
+            ("rules", self._validate_rules()?),
+            ("pairs", self._validate_pairs()?),
+            ("lines", self._validate_paths()?),
+            ("clues", self._validate_clues()?),
+            ("cages", self._validate_cages()?),
+        ];
+        for (kind, valid) in constraints {
+            if !valid {
+                violations.push(Violation::CONSTRAINT {
+                    kind: String::from(kind),
+                });
+            }
+        }
+        Ok(violations)
     }
 
     /*
-     * Line verification
+     * Line, column, box and extra region verification
+     *
+     * Values must not repeat within any of them. Stops at the first repeated
+     * value, unless all are asked for.
      *
      */
-    fn _validate_line(&self) -> AnyhowResult<bool> {
-        for line in &self.line {
-            let mut test: Vec<usize> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-            for square_id in line.get_square_ids() {
-                let square = self.get_square(square_id)?;
-                if square.value == 0 {
+    fn _validate_units(&self, first: bool) -> AnyhowResult<Vec<Violation>> {
+        let units = self
+            .line
+            .iter()
+            .map(|x| (Unit::LINE, x._id, x.get_square_ids()))
+            .chain(
+                self.column
+                    .iter()
+                    .map(|x| (Unit::COLUMN, x._id, x.get_square_ids())),
+            )
+            .chain(
+                self.abox
+                    .iter()
+                    .map(|x| (Unit::ABOX, x._id, x.get_square_ids())),
+            )
+            .chain(
+                self.region
+                    .iter()
+                    .map(|x| (Unit::REGION, x._id, x.get_square_ids())),
+            );
+
+        let mut violations: Vec<Violation> = Vec::new();
+        for (unit, id, square_ids) in units {
+            let squares = square_ids
+                .iter()
+                .map(|x| self.get_square(*x))
+                .collect::<AnyhowResult<Vec<&Square>>>()?;
+            for value in 1..=9 {
+                let places: Vec<Place> = squares
+                    .iter()
+                    .filter(|x| x.value == value)
+                    .map(|x| Place::of(x))
+                    .collect();
+                if places.len() < 2 {
                     continue;
                 }
-                if !helpers::remove_element(square.value, &mut test) {
-                    log::debug!(
-                        "[validation] failed on line: {:?}, duplicate value: {:?}",
-                        line._id,
-                        square.value
-                    );
-                    return Ok(false);
+                log::debug!("[validation] failed on {unit}: {id:?}, duplicate value: {value:?}");
+                violations.push(Violation::DUPLICATE {
+                    unit: unit.clone(),
+                    id,
+                    value,
+                    squares: places,
+                });
+                if first {
+                    return Ok(violations);
                 }
             }
         }
-        Ok(true)
+        Ok(violations)
     }
 
     /*
-     * Column verification
+     * Rule verification
      *
      */
-    fn _validate_column(&self) -> AnyhowResult<bool> {
-        for column in &self.column {
-            let mut test: Vec<usize> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-            for square_id in column.get_square_ids() {
-                let square = self.get_square(square_id)?;
+    fn _validate_rules(&self) -> AnyhowResult<bool> {
+        for rule in &self.rules {
+            for square in &self.squares {
                 if square.value == 0 {
                     continue;
                 }
-                if !helpers::remove_element(square.value, &mut test) {
-                    log::debug!(
-                        "[validation] failed on column: {:?}, duplicate value: {:?}",
-                        column._id,
-                        square.value
-                    );
-                    return Ok(false);
+                for neighbour_id in rules::neighbours(rule, square.id) {
+                    if self.get_square(neighbour_id)?.value == square.value {
+                        log::debug!(
+                            "[validation] failed on {rule}: {:?} and {neighbour_id:?}, duplicate value: {:?}",
+                            square.id,
+                            square.value
+                        );
+                        return Ok(false);
+                    }
                 }
             }
         }
@@ -966,27 +1723,113 @@ impl Table {
     }
 
     /*
-     * Box verification
+     * Pair verification
      *
      */
-    fn _validate_box(&self) -> AnyhowResult<bool> {
-        for abox in &self.abox {
-            let mut test: Vec<usize> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-            for square_id in abox.get_square_ids() {
-                let square = self.get_square(square_id)?;
-                if square.value == 0 {
-                    continue;
-                }
-                if !helpers::remove_element(square.value, &mut test) {
-                    log::debug!(
-                        "[validation] failed on box: {:?}, duplicate value: {:?}",
-                        abox._id,
-                        square.value
-                    );
-                    return Ok(false);
+    fn _validate_pairs(&self) -> AnyhowResult<bool> {
+        for pair in &self.pairs {
+            let a = self.get_square(pair._0)?.value;
+            let b = self.get_square(pair._1)?.value;
+            if a != 0 && b != 0 && !pair.kind.holds(a, b) {
+                log::debug!(
+                    "[validation] failed on {} pair: {:?} and {:?}, values: {a:?} {b:?}",
+                    pair.kind,
+                    pair._0,
+                    pair._1
+                );
+                return Ok(false);
+            }
+        }
+
+        for kind in &self.negative {
+            for square in &self.squares {
+                for other_id in [square.id + 1, square.id + 9] {
+                    if other_id >= 81 || !pair::adjacent(square.id, other_id) {
+                        continue;
+                    }
+                    if self.get_pair_kind(square.id, other_id).is_some() {
+                        continue;
+                    }
+
+                    let other = self.get_square(other_id)?;
+                    if square.value != 0
+                        && other.value != 0
+                        && kind.holds(square.value, other.value)
+                    {
+                        log::debug!(
+                            "[validation] failed on negative {kind}: {:?} and {other_id:?}",
+                            square.id
+                        );
+                        return Ok(false);
+                    }
                 }
             }
         }
         Ok(true)
     }
+
+    /*
+     * Line constraint verification
+     *
+     */
+    fn _validate_paths(&self) -> AnyhowResult<bool> {
+        for path in &self.paths {
+            let mut values: Vec<usize> = Vec::new();
+            for square_id in path.get_square_ids() {
+                values.push(self.get_square(square_id)?.value);
+            }
+            if !path.valid(&values) {
+                log::debug!(
+                    "[validation] failed on {}: {:?}, values: {values:?}",
+                    path.kind,
+                    path._squares
+                );
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /*
+     * Clue verification
+     *
+     */
+    fn _validate_clues(&self) -> AnyhowResult<bool> {
+        for clue in &self.clues {
+            let mut values: Vec<usize> = Vec::new();
+            for square_id in clue.get_square_ids() {
+                values.push(self.get_square(square_id)?.value);
+            }
+            if !clue.valid(&values) {
+                log::debug!(
+                    "[validation] failed on {} clue: {:?}, values: {values:?}",
+                    clue.kind,
+                    clue.sum
+                );
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /*
+     * Cage verification
+     *
+     */
+    fn _validate_cages(&self) -> AnyhowResult<bool> {
+        for cage in &self.cages {
+            let mut values: Vec<usize> = Vec::new();
+            for square_id in cage.get_square_ids() {
+                values.push(self.get_square(square_id)?.value);
+            }
+            if !cage.valid(&values) {
+                log::debug!(
+                    "[validation] failed on cage: {:?}, values: {values:?}",
+                    cage._squares
+                );
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
@@ -1,45 +1,464 @@
+use crate::components::square::Square;
 use crate::components::table;
+use crate::enums::{PairKind, SetKind, Side};
+use std::fmt::Write;
 
 /*
  * Draw the Table
+ *
+ * Clues outside the table are drawn around the frame, sandwich and little
+ * killer sums next to the line or column they belong to. With color, values
+ * are styled by where they came from and a legend is drawn below.
  */
-pub fn draw_table(table: &table::core::Table, draw: bool) {
-    if !draw {
-        return;
+pub fn draw_table(table: &table::core::Table, draw: bool, color: bool) {
+    if draw {
+        print!("{}", render_table(table, color));
     }
+}
+
+/*
+ * The Table as drawn by draw_table
+ */
+pub fn render_table(table: &table::core::Table, color: bool) -> String {
+    let mut out = String::new();
+    if table.get_grids().len() > 1 {
+        render_grids(&mut out, table, false, color);
+        legend(&mut out, color);
+        return out;
+    }
+
+    let left: Vec<String> = labels(table, Side::LEFT)
+        .iter()
+        .map(|x| x.join(" "))
+        .collect();
+    let right: Vec<String> = labels(table, Side::RIGHT)
+        .iter()
+        .map(|x| x.join(" "))
+        .collect();
+    let top = labels(table, Side::TOP);
+    let bottom = labels(table, Side::BOTTOM);
+
+    // Left margin is as wide as the widest clue, plus a space
+    let width = match left.iter().map(|x| x.chars().count()).max() {
+        Some(0) | None => 0,
+        Some(n) => n + 1,
+    };
+    let margin = " ".repeat(width);
 
-    print!("╔═══╤═══╤═══╦═══╤═══╤═══╦═══╤═══╤═══╗");
-    let mut c = 0;
-    for i in 0..81_usize {
-        match i {
-            27 | 54 => {
-                println!();
-                print!("╠═══╪═══╪═══╬═══╪═══╪═══╬═══╪═══╪═══╣");
+    for row in outside(&top) {
+        let _ = writeln!(out, "{margin}{row}");
+    }
+
+    let values: Vec<usize> = table.squares.iter().map(|x| x.value).collect();
+    for (i, row) in board(table, &values, color).iter().enumerate() {
+        if i % 2 == 0 {
+            let _ = writeln!(out, "{margin}{row}");
+            continue;
+        }
+
+        let line = i / 2;
+        let _ = match width {
+            0 => write!(out, "{row}"),
+            _ => write!(out, "{:>w$} {row}", left[line], w = width - 1),
+        };
+        let _ = match right[line].as_str() {
+            "" => writeln!(out),
+            label => writeln!(out, " {label}"),
+        };
+    }
+
+    for row in outside(&bottom) {
+        let _ = writeln!(out, "{margin}{row}");
+    }
+    legend(&mut out, color);
+    out
+}
+
+/*
+ * Rows of the frame and values of a single grid table
+ *
+ * Values are given per square, 0 is an empty square. Every other row is a
+ * line of squares, starting and ending with a border row. Square c of such
+ * a row is drawn at characters 4 * c + 1 to 4 * c + 3, not counting color.
+ */
+pub fn board(table: &table::core::Table, values: &[usize], color: bool) -> Vec<String> {
+    let mut rows: Vec<String> = vec![frame(['╔', '═', '╤', '╦', '╗'], 3)];
+    for line in 0..9_usize {
+        match line {
+            3 | 6 => rows.push(separator(table, line * 9, ['╠', '═', '╪', '╬', '╣'], 3)),
+            0 => (),
+            _ => rows.push(separator(table, line * 9, ['╟', '─', '┼', '╫', '╢'], 3)),
+        }
+
+        let mut row = String::from("║"); // beginning
+        for c in 0..9_usize {
+            let i = line * 9 + c;
+            match values[i] {
+                0 => row.push_str("   "),
+                value => row.push_str(&format!(" {} ", paint(&table.squares[i], value, color))),
             }
-            9 | 18 | 36 | 45 | 63 | 72 => {
-                println!();
-                print!("╟───┼───┼───╫───┼───┼───╫───┼───┼───╢");
+            if c == 8 {
+                row.push('║');
+            } else if let Some(kind) = table.get_pair_kind(i, i + 1) {
+                row.push(marker(kind));
+            } else if c == 2 || c == 5 {
+                row.push('║');
+            } else {
+                row.push('│');
             }
-            _ => (),
         }
-        if i % 9 == 0 {
-            println!();
-            print!("║"); // beginning
-            c = 0;
+        rows.push(row);
+    }
+    rows.push(frame(['╚', '═', '╧', '╩', '╝'], 3));
+    rows
+}
+
+/*
+ * Draw the Table with candidates
+ *
+ * Each square is drawn as a 3x3 mini-grid of its potentials, a set square
+ * shows its value in the middle. Potentials are only known after an update,
+ * so draw after Table::update. Several grids are drawn on a canvas as by
+ * draw_table, with the same mini-grids.
+ */
+pub fn draw_candidates(table: &table::core::Table, draw: bool, color: bool) {
+    if draw {
+        print!("{}", render_candidates(table, color));
+    }
+}
+
+/*
+ * The Table as drawn by draw_candidates
+ */
+pub fn render_candidates(table: &table::core::Table, color: bool) -> String {
+    let mut out = String::new();
+    if table.get_grids().len() > 1 {
+        render_grids(&mut out, table, true, color);
+        legend(&mut out, color);
+        return out;
+    }
+
+    let _ = writeln!(out, "{}", frame(['╔', '═', '╤', '╦', '╗'], 7));
+    for line in 0..9_usize {
+        let _ = match line {
+            3 | 6 => writeln!(
+                out,
+                "{}",
+                separator(table, line * 9, ['╠', '═', '╪', '╬', '╣'], 7)
+            ),
+            0 => Ok(()),
+            _ => writeln!(
+                out,
+                "{}",
+                separator(table, line * 9, ['╟', '─', '┼', '╫', '╢'], 7)
+            ),
         };
 
-        let value = table.squares[i].value;
-        match value {
-            0 => print!("   "),
-            _ => print!(" {value} "),
+        for r in 0..3_usize {
+            let mut row = String::from("║"); // beginning
+            for c in 0..9_usize {
+                let i = line * 9 + c;
+                let square = &table.squares[i];
+                match square.value {
+                    0 => {
+                        let digits: Vec<String> = (r * 3 + 1..=r * 3 + 3)
+                            .map(|x| match square.potentials.contains(&x) {
+                                true => x.to_string(),
+                                false => String::from(" "),
+                            })
+                            .collect();
+                        row.push_str(&format!(" {} ", digits.join(" ")));
+                    }
+                    value if r == 1 => {
+                        row.push_str(&format!("   {}   ", paint(square, value, color)))
+                    }
+                    _ => row.push_str("       "),
+                }
+
+                if c == 8 {
+                    row.push('║');
+                } else if let (1, Some(kind)) = (r, table.get_pair_kind(i, i + 1)) {
+                    row.push(marker(kind));
+                } else if c == 2 || c == 5 {
+                    row.push('║');
+                } else {
+                    row.push('│');
+                }
+            }
+            let _ = writeln!(out, "{row}");
         }
-        if c == 2 || c == 5 || c == 8 {
-            print!("║");
-        } else {
-            print!("│");
+    }
+    let _ = writeln!(out, "{}", frame(['╚', '═', '╧', '╩', '╝'], 7));
+    legend(&mut out, color);
+    out
+}
+
+/*
+ * Top or bottom border of a single grid table
+ *
+ * Characters are given as [left, horizontal, cross, box cross, right].
+ */
+fn frame(chars: [char; 5], width: usize) -> String {
+    let [left, horizontal, cross, box_cross, right] = chars;
+    let mut result = String::from(left);
+
+    for c in 0..9 {
+        result.extend(vec![horizontal; width]);
+        match c {
+            2 | 5 => result.push(box_cross),
+            8 => result.push(right),
+            _ => result.push(cross),
+        }
+    }
+    result
+}
+
+/*
+ * Draw a table of several overlapping grids
+ *
+ * The grids are drawn on a canvas, each square as a cell of its own. Box
+ * boundaries and the outer border are drawn with double lines, other
+ * boundaries with single lines. With candidates, a cell is a 3x3 mini-grid of
+ * the potentials of the square as drawn by render_candidates.
+ */
+fn render_grids(out: &mut String, table: &table::core::Table, candidates: bool, color: bool) {
+    // Square id per (line, column) on the canvas
+    let mut cells: Vec<Vec<Option<usize>>> = Vec::new();
+    for grid in table.get_grids() {
+        for line in 0..9 {
+            for column in 0..9 {
+                let (l, c) = (grid.line + line, grid.column + column);
+                if cells.len() <= l {
+                    cells.resize(l + 1, Vec::new());
+                }
+                if cells[l].len() <= c {
+                    cells[l].resize(c + 1, None);
+                }
+                cells[l][c] = Some(grid.get_square_id(line, column));
+            }
+        }
+    }
+
+    // Characters across and rows down of a cell
+    let (across, rows) = match candidates {
+        true => (7, 3),
+        false => (3, 1),
+    };
+
+    let height = cells.len();
+    let width = cells.iter().map(|x| x.len()).max().unwrap_or(0);
+    let cell = |l: i32, c: i32| -> bool {
+        l >= 0
+            && c >= 0
+            && cells
+                .get(l as usize)
+                .and_then(|x| x.get(c as usize))
+                .is_some_and(|x| x.is_some())
+    };
+
+    // Weight of the boundary between two cells: 0 none, 1 single, 2 double
+    let weight = |a: bool, b: bool, boundary: bool| -> usize {
+        match (a, b) {
+            (false, false) => 0,
+            (true, true) if !boundary => 1,
+            _ => 2,
         }
-        c += 1;
+    };
+
+    for l in 0..=height as i32 {
+        // Boundary above line l
+        let mut row = String::new();
+        for c in 0..=width as i32 {
+            let up = weight(cell(l - 1, c - 1), cell(l - 1, c), c % 3 == 0);
+            let down = weight(cell(l, c - 1), cell(l, c), c % 3 == 0);
+            let left = weight(cell(l - 1, c - 1), cell(l, c - 1), l % 3 == 0);
+            let right = weight(cell(l - 1, c), cell(l, c), l % 3 == 0);
+            row.push(junction(up, down, left, right));
+
+            if c < width as i32 {
+                let horizontal = match right {
+                    0 => ' ',
+                    1 => '─',
+                    _ => '═',
+                };
+                row.extend(std::iter::repeat_n(horizontal, across));
+            }
+        }
+        let _ = writeln!(out, "{}", row.trim_end());
+
+        if l == height as i32 {
+            break;
+        }
+
+        // Squares of line l
+        for r in 0..rows {
+            let mut row = String::new();
+            for c in 0..=width as i32 {
+                row.push(match weight(cell(l, c - 1), cell(l, c), c % 3 == 0) {
+                    0 => ' ',
+                    1 => '│',
+                    _ => '║',
+                });
+
+                if c < width as i32 {
+                    let id = cells[l as usize].get(c as usize).copied().flatten();
+                    let square = id.map(|x| &table.squares[x]);
+                    match square {
+                        Some(square) if square.value == 0 && candidates => {
+                            let digits: Vec<String> = (r * 3 + 1..=r * 3 + 3)
+                                .map(|x| match square.potentials.contains(&x) {
+                                    true => x.to_string(),
+                                    false => String::from(" "),
+                                })
+                                .collect();
+                            row.push_str(&format!(" {} ", digits.join(" ")));
+                        }
+                        Some(square) if square.value != 0 && r == rows / 2 => {
+                            let pad = " ".repeat(across / 2);
+                            row.push_str(&format!(
+                                "{pad}{}{pad}",
+                                paint(square, square.value, color)
+                            ));
+                        }
+                        _ => row.push_str(&" ".repeat(across)),
+                    }
+                }
+            }
+            let _ = writeln!(out, "{}", row.trim_end());
+        }
+    }
+}
+
+/*
+ * Box drawing character where boundaries meet
+ *
+ * Each direction has a weight: 0 none, 1 single, 2 double.
+ */
+fn junction(up: usize, down: usize, left: usize, right: usize) -> char {
+    let v = if up.max(down) == 2 { 1 } else { 0 };
+    let h = if left.max(right) == 2 { 1 } else { 0 };
+    let chars: [char; 4] = match (up > 0, down > 0, left > 0, right > 0) {
+        (true, true, true, true) => ['┼', '╪', '╫', '╬'],
+        (true, true, false, true) => ['├', '╞', '╟', '╠'],
+        (true, true, true, false) => ['┤', '╡', '╢', '╣'],
+        (false, true, true, true) => ['┬', '╤', '╥', '╦'],
+        (true, false, true, true) => ['┴', '╧', '╨', '╩'],
+        (false, true, false, true) => ['┌', '╒', '╓', '╔'],
+        (false, true, true, false) => ['┐', '╕', '╖', '╗'],
+        (true, false, false, true) => ['└', '╘', '╙', '╚'],
+        (true, false, true, false) => ['┘', '╛', '╜', '╝'],
+        (true, true, false, false) | (true, false, false, false) | (false, true, false, false) => {
+            ['│', '│', '║', '║']
+        }
+        (false, false, true, true) | (false, false, true, false) | (false, false, false, true) => {
+            ['─', '═', '─', '═']
+        }
+        (false, false, false, false) => [' ', ' ', ' ', ' '],
+    };
+    // Index by [single, double horizontal, double vertical, double both]
+    chars[h + 2 * v]
+}
+
+/*
+ * Clue labels on one side of the table, per line or column
+ */
+fn labels(table: &table::core::Table, side: Side) -> Vec<Vec<String>> {
+    let mut result: Vec<Vec<String>> = vec![Vec::new(); 9];
+    for clue in table.get_clues().iter().filter(|x| x.side == side) {
+        result[clue.index].push(clue.label());
+    }
+    result
+}
+
+/*
+ * Rows of clue labels above or below the table
+ *
+ * Each label ends right below (or above) the value of its column. Several
+ * clues on the same column are drawn on rows of their own.
+ */
+fn outside(labels: &[Vec<String>]) -> Vec<String> {
+    let rows = labels.iter().map(|x| x.len()).max().unwrap_or(0);
+    let mut result: Vec<String> = Vec::new();
+
+    for r in 0..rows {
+        let mut row: Vec<char> = vec![' '; 37];
+        for (c, column_labels) in labels.iter().enumerate() {
+            let Some(label) = column_labels.get(r) else {
+                continue;
+            };
+            let chars: Vec<char> = label.chars().collect();
+            let start = (4 * c + 3).saturating_sub(chars.len());
+            for (i, ch) in chars.into_iter().enumerate() {
+                row[start + i] = ch;
+            }
+        }
+        result.push(row.into_iter().collect::<String>().trim_end().to_string());
+    }
+    result
+}
+
+/*
+ * Separator line above the line starting with square id
+ *
+ * Characters are given as [left, horizontal, cross, box cross, right]. A
+ * pair marker between two squares is drawn in the middle of the square,
+ * which is width characters wide.
+ */
+fn separator(table: &table::core::Table, id: usize, chars: [char; 5], width: usize) -> String {
+    let [left, horizontal, cross, box_cross, right] = chars;
+    let mut result = String::from(left);
+
+    for c in 0..9 {
+        let middle = match table.get_pair_kind(id - 9 + c, id + c) {
+            Some(kind) => marker(kind),
+            None => horizontal,
+        };
+        result.extend(vec![horizontal; width / 2]);
+        result.push(middle);
+        result.extend(vec![horizontal; width / 2]);
+
+        match c {
+            2 | 5 => result.push(box_cross),
+            8 => result.push(right),
+            _ => result.push(cross),
+        }
+    }
+    result
+}
+
+/*
+ * Marker drawn between two squares of a pair
+ */
+fn marker(kind: &PairKind) -> char {
+    match kind {
+        PairKind::WHITE => '○',
+        PairKind::BLACK => '●',
+        PairKind::X => 'X',
+        PairKind::V => 'V',
+    }
+}
+
+/*
+ * Value styled by where it came from
+ *
+ * Givens are bold, deductions normal and guesses black on yellow.
+ */
+fn paint(square: &Square, value: usize, color: bool) -> String {
+    match (color, &square.kind) {
+        (true, Some(SetKind::GIVEN)) => format!("\x1b[1m{value}\x1b[0m"),
+        (true, Some(SetKind::GUESS)) => format!("\x1b[30;43m{value}\x1b[0m"),
+        _ => value.to_string(),
+    }
+}
+
+/*
+ * Legend of the colors used by paint
+ */
+fn legend(out: &mut String, color: bool) {
+    if color {
+        let _ = writeln!(
+            out,
+            "\x1b[1mgiven\x1b[0m  deduced  \x1b[30;43mguessed\x1b[0m"
+        );
     }
-    println!();
-    println!("╚═══╧═══╧═══╩═══╧═══╧═══╩═══╧═══╧═══╝");
 }
@@ -0,0 +1,268 @@
+use crate::components::square::Square;
+use crate::components::table::core::Table;
+use crate::components::table::draw::{render_candidates, render_table};
+use crate::components::table::svg::render_svg;
+use crate::enums::{ClueKind, Rule};
+use std::fmt::Write;
+
+/*
+ * Render a Table as text
+ *
+ * Each output style is a Renderer, new ones plug in by implementing render,
+ * and render_candidates for the potentials of empty squares as well. These
+ * are only known after Table::update.
+ */
+pub trait Renderer {
+    fn render(&self, table: &Table) -> String;
+    fn render_candidates(&self, table: &Table) -> String;
+}
+
+/*
+ * Box drawing characters, as drawn by draw_table
+ */
+pub struct Unicode {
+    pub color: bool,
+}
+
+impl Renderer for Unicode {
+    fn render(&self, table: &Table) -> String {
+        render_table(table, self.color)
+    }
+
+    fn render_candidates(&self, table: &Table) -> String {
+        render_candidates(table, self.color)
+    }
+}
+
+/*
+ * Plain ASCII, the Unicode layout with +---+ borders
+ *
+ * Box boundaries are drawn with = instead of -, pair markers and little
+ * killer arrows are replaced by ASCII characters.
+ */
+pub struct Ascii;
+
+impl Renderer for Ascii {
+    fn render(&self, table: &Table) -> String {
+        ascii(&render_table(table, false))
+    }
+
+    fn render_candidates(&self, table: &Table) -> String {
+        ascii(&render_candidates(table, false))
+    }
+}
+
+fn ascii(text: &str) -> String {
+    text.chars()
+        .map(|x| match x {
+            '─' => '-',
+            '═' => '=',
+            '│' | '║' => '|',
+            '○' => 'o',
+            '●' => '*',
+            '↘' | '↖' => '\\',
+            '↙' | '↗' => '/',
+            x if ('\u{2500}'..='\u{257f}').contains(&x) => '+',
+            x => x,
+        })
+        .collect()
+}
+
+/*
+ * One line of digits per line of a grid, . for an empty square
+ *
+ * Several grids are written one after the other, separated by an empty line.
+ * With candidates, squares are separated by a space and an empty square is
+ * its candidates in brackets, e.g. [139].
+ */
+pub struct Compact;
+
+impl Renderer for Compact {
+    fn render(&self, table: &Table) -> String {
+        compact(table, "", |square| match square.value {
+            0 => String::from("."),
+            value => value.to_string(),
+        })
+    }
+
+    fn render_candidates(&self, table: &Table) -> String {
+        compact(table, " ", |square| match square.value {
+            0 => format!("[{}]", digits(square, "")),
+            value => value.to_string(),
+        })
+    }
+}
+
+fn compact(table: &Table, separator: &str, text: impl Fn(&Square) -> String) -> String {
+    let grids: Vec<String> = table
+        .get_grids()
+        .iter()
+        .map(|grid| {
+            let mut out = String::new();
+            for line in 0..9 {
+                let squares: Vec<String> = (0..9)
+                    .map(|x| text(&table.squares[grid.get_square_id(line, x)]))
+                    .collect();
+                let _ = writeln!(out, "{}", squares.join(separator));
+            }
+            out
+        })
+        .collect();
+    grids.join("\n")
+}
+
+/*
+ * Candidates of a square in order, joined by separator
+ */
+fn digits(square: &Square, separator: &str) -> String {
+    let mut potentials = square.potentials.clone();
+    potentials.sort();
+    let digits: Vec<String> = potentials.iter().map(|x| x.to_string()).collect();
+    digits.join(separator)
+}
+
+/*
+ * Verbose text for screen readers
+ *
+ * Each row is read out, value by value, followed by the variant rules in
+ * words. Several grids are read out grid by grid. With candidates, an empty
+ * square is read out as its candidates.
+ */
+pub struct Accessible;
+
+impl Renderer for Accessible {
+    fn render(&self, table: &Table) -> String {
+        accessible(table, |square| match square.value {
+            0 => String::from("empty"),
+            value => value.to_string(),
+        })
+    }
+
+    fn render_candidates(&self, table: &Table) -> String {
+        accessible(table, |square| {
+            match (square.value, square.potentials.len()) {
+                (0, 0) => String::from("no candidates"),
+                (0, _) => format!("candidates {}", digits(square, " ")),
+                (value, _) => value.to_string(),
+            }
+        })
+    }
+}
+
+fn accessible(table: &Table, text: impl Fn(&Square) -> String) -> String {
+    let mut out = String::new();
+    let grids = table.get_grids();
+    for (i, grid) in grids.iter().enumerate() {
+        for line in 0..9 {
+            let values: Vec<String> = (0..9)
+                .map(|x| text(&table.squares[grid.get_square_id(line, x)]))
+                .collect();
+            let row = match grids.len() {
+                1 => format!("Row {}", line + 1),
+                _ => format!("Grid {}, row {}", i + 1, line + 1),
+            };
+            let _ = writeln!(out, "{row}: {}.", values.join(", "));
+        }
+    }
+
+    for rule in table.get_rules() {
+        let _ = match rule {
+            Rule::ANTIKNIGHT => writeln!(
+                out,
+                "Anti-knight: equal digits are never a knight's move apart."
+            ),
+            Rule::ANTIKING => writeln!(
+                out,
+                "Anti-king: equal digits are never a king's move apart."
+            ),
+        };
+    }
+    for region in table.get_regions() {
+        let _ = writeln!(
+            out,
+            "Region, digits 1 to 9 once: {}.",
+            squares(&region.get_square_ids())
+        );
+    }
+    for cage in table.get_cages() {
+        let _ = writeln!(
+            out,
+            "Cage summing to {}: {}.",
+            cage.sum,
+            squares(&cage.get_square_ids())
+        );
+    }
+    for pair in table.get_pairs() {
+        let _ = writeln!(
+            out,
+            "Pair {} between {}.",
+            pair.kind,
+            squares(&pair.get_square_ids()).replace(", ", " and ")
+        );
+    }
+    for kind in table.get_negative() {
+        let _ = writeln!(
+            out,
+            "Negative {kind}: adjacent squares without a {kind} marker never satisfy it."
+        );
+    }
+    for path in table.get_paths() {
+        let _ = writeln!(
+            out,
+            "Line {}: {}.",
+            path.kind,
+            squares(&path.get_square_ids())
+        );
+    }
+    for clue in table.get_clues() {
+        let squares = clue.get_square_ids();
+        let _ = match clue.kind {
+            ClueKind::SANDWICH if clue.direction == (0, 1) => {
+                writeln!(out, "Sandwich of {} in row {}.", clue.sum, clue.index + 1)
+            }
+            ClueKind::SANDWICH => writeln!(
+                out,
+                "Sandwich of {} in column {}.",
+                clue.sum,
+                clue.index + 1
+            ),
+            ClueKind::LITTLEKILLER => writeln!(
+                out,
+                "Little killer of {}, diagonal from {} to {}.",
+                clue.sum,
+                square(squares[0]),
+                square(squares[squares.len() - 1])
+            ),
+        };
+    }
+    out
+}
+
+/*
+ * SVG image, as written by --render svg
+ */
+pub struct Svg {
+    pub candidates: bool,
+}
+
+impl Renderer for Svg {
+    fn render(&self, table: &Table) -> String {
+        render_svg(table, self.candidates)
+    }
+
+    fn render_candidates(&self, table: &Table) -> String {
+        render_svg(table, true)
+    }
+}
+
+/*
+ * Square of a single grid table, as "row 1 column 2"
+ */
+fn square(id: usize) -> String {
+    format!("row {} column {}", id / 9 + 1, id % 9 + 1)
+}
+
+fn squares(ids: &[usize]) -> String {
+    let squares: Vec<String> = ids.iter().map(|x| square(*x)).collect();
+    squares.join(", ")
+}
@@ -0,0 +1,106 @@
+use crate::enums::Rule;
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+impl FromStr for Rule {
+    type Err = anyhow::Error;
+
+    fn from_str(rule: &str) -> AnyhowResult<Rule> {
+        match rule {
+            "anti-knight" => Ok(Rule::ANTIKNIGHT),
+            "anti-king" => Ok(Rule::ANTIKING),
+            _ => Err(anyhow!("Unsupported rule: {rule:?}")),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::ANTIKNIGHT => write!(f, "anti-knight"),
+            Rule::ANTIKING => write!(f, "anti-king"),
+        }
+    }
+}
+
+/*
+ * Offsets (line, column) a chess piece can move from a square
+ *
+ */
+fn offsets(rule: &Rule) -> &'static [(i32, i32)] {
+    match rule {
+        Rule::ANTIKNIGHT => &[
+            (-2, -1),
+            (-2, 1),
+            (-1, -2),
+            (-1, 2),
+            (1, -2),
+            (1, 2),
+            (2, -1),
+            (2, 1),
+        ],
+        Rule::ANTIKING => &[
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ],
+    }
+}
+
+/*
+ * Get square ids in the neighbourhood of a square
+ *
+ * Unlike Line, Column and ABox these are not containers of nine squares.
+ * Every square has its own neighbourhood, which is the squares a knight or a
+ * king can reach in one move. None of them may hold the same value.
+ *
+ */
+pub fn neighbours(rule: &Rule, square_id: usize) -> Vec<usize> {
+    let line = (square_id / 9) as i32;
+    let column = (square_id % 9) as i32;
+
+    offsets(rule)
+        .iter()
+        .map(|(l, c)| (line + l, column + c))
+        .filter(|(l, c)| (0..9).contains(l) && (0..9).contains(c))
+        .map(|(l, c)| (l * 9 + c) as usize)
+        .collect()
+}
+
+/*
+ * A potential removed by a rule
+ *
+ * The value can not be in the square, the square seen_by holds it and is a
+ * knight's or a king's move away.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Elimination {
+    pub rule: Rule,
+    pub square_id: usize,
+    pub value: usize,
+    pub seen_by: usize,
+}
+
+impl fmt::Display for Elimination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} removes {} from line {}, column {}, seen from line {}, column {}",
+            self.rule,
+            self.value,
+            self.square_id / 9 + 1,
+            self.square_id % 9 + 1,
+            self.seen_by / 9 + 1,
+            self.seen_by % 9 + 1
+        )
+    }
+}
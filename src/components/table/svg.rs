@@ -0,0 +1,237 @@
+use crate::components::table::core::Table;
+use crate::enums::{PairKind, PathKind, SetKind, Side};
+use std::fmt::Write;
+
+// Size of a square and of the margin around the grid, for clues
+const CELL: usize = 50;
+const MARGIN: usize = 60;
+
+/*
+ * Render the Table as an SVG image
+ *
+ * Givens are drawn bold and black, other values blue. Extra regions are
+ * shaded, cages dashed and lines, pairs and clues drawn as in print. With
+ * candidates, empty squares show their potentials as small digits.
+ */
+pub fn render_svg(table: &Table, candidates: bool) -> String {
+    let positions = positions(table);
+    let lines = positions.iter().map(|x| x.0 + 1).max().unwrap_or(9);
+    let columns = positions.iter().map(|x| x.1 + 1).max().unwrap_or(9);
+    let (width, height) = (columns * CELL + 2 * MARGIN, lines * CELL + 2 * MARGIN);
+
+    // Center of a square
+    let center = |id: usize| -> (usize, usize) {
+        let (l, c) = positions[id];
+        (MARGIN + c * CELL + CELL / 2, MARGIN + l * CELL + CELL / 2)
+    };
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif">"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect width="{width}" height="{height}" fill="white"/>"#
+    );
+
+    // Extra regions
+    for region in table.get_regions() {
+        for id in region.get_square_ids() {
+            let (x, y) = corner(positions[id]);
+            let _ = writeln!(
+                svg,
+                r##"<rect x="{x}" y="{y}" width="{CELL}" height="{CELL}" fill="#e8e8e8"/>"##
+            );
+        }
+    }
+
+    // Lines under the squares and values
+    for path in table.get_paths() {
+        let points: Vec<String> = path
+            .get_square_ids()
+            .iter()
+            .map(|x| center(*x))
+            .map(|(x, y)| format!("{x},{y}"))
+            .collect();
+        let (x, y) = center(path._squares[0]);
+        let (stroke, width) = match path.kind {
+            PathKind::THERMO => ("#c0c0c0", 14),
+            PathKind::ARROW => ("#808080", 3),
+            PathKind::PALINDROME => ("#99c1f1", 10),
+        };
+        let _ = writeln!(
+            svg,
+            r#"<polyline points="{}" fill="none" stroke="{stroke}" stroke-width="{width}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+            points.join(" ")
+        );
+        match path.kind {
+            PathKind::THERMO => {
+                let _ = writeln!(
+                    svg,
+                    r##"<circle cx="{x}" cy="{y}" r="18" fill="#c0c0c0"/>"##
+                );
+            }
+            PathKind::ARROW => {
+                let _ = writeln!(
+                    svg,
+                    r##"<circle cx="{x}" cy="{y}" r="20" fill="white" stroke="#808080" stroke-width="3"/>"##
+                );
+            }
+            PathKind::PALINDROME => (),
+        }
+    }
+
+    // Squares
+    for position in &positions {
+        let (x, y) = corner(*position);
+        let _ = writeln!(
+            svg,
+            r##"<rect x="{x}" y="{y}" width="{CELL}" height="{CELL}" fill="none" stroke="#808080" stroke-width="1"/>"##
+        );
+    }
+
+    // Boxes and the border of each grid
+    for grid in table.get_grids() {
+        for b in 0..9 {
+            let (x, y) = corner((grid.line + 3 * (b / 3), grid.column + 3 * (b % 3)));
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{x}" y="{y}" width="{}" height="{}" fill="none" stroke="black" stroke-width="3"/>"#,
+                3 * CELL,
+                3 * CELL
+            );
+        }
+    }
+
+    // Cages, dashed inside the squares, the sum in the top-left square
+    for cage in table.get_cages() {
+        let ids = cage.get_square_ids();
+        let inside = |l: i64, c: i64| -> bool {
+            ids.iter()
+                .any(|x| positions[*x].0 as i64 == l && positions[*x].1 as i64 == c)
+        };
+        for id in &ids {
+            let (l, c) = (positions[*id].0 as i64, positions[*id].1 as i64);
+            let (x, y) = corner(positions[*id]);
+            let (x0, y0, x1, y1) = (x + 4, y + 4, x + CELL - 4, y + CELL - 4);
+            let edges = [
+                (!inside(l - 1, c), (x0, y0, x1, y0)),
+                (!inside(l + 1, c), (x0, y1, x1, y1)),
+                (!inside(l, c - 1), (x0, y0, x0, y1)),
+                (!inside(l, c + 1), (x1, y0, x1, y1)),
+            ];
+            for (_, (ax, ay, bx, by)) in edges.iter().filter(|x| x.0) {
+                let _ = writeln!(
+                    svg,
+                    r#"<line x1="{ax}" y1="{ay}" x2="{bx}" y2="{by}" stroke="black" stroke-width="1" stroke-dasharray="4 3"/>"#
+                );
+            }
+        }
+        if let Some(first) = ids.iter().min_by_key(|x| positions[**x]) {
+            let (x, y) = corner(positions[*first]);
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-size="11" fill="black">{}</text>"#,
+                x + 6,
+                y + 15,
+                cage.sum
+            );
+        }
+    }
+
+    // Pairs, on the boundary between the squares
+    for pair in table.get_pairs() {
+        let ((ax, ay), (bx, by)) = (center(pair._0), center(pair._1));
+        let (x, y) = ((ax + bx) / 2, (ay + by) / 2);
+        let _ = match pair.kind {
+            PairKind::WHITE => writeln!(
+                svg,
+                r#"<circle cx="{x}" cy="{y}" r="7" fill="white" stroke="black" stroke-width="1.5"/>"#
+            ),
+            PairKind::BLACK => writeln!(svg, r#"<circle cx="{x}" cy="{y}" r="7" fill="black"/>"#),
+            PairKind::X | PairKind::V => writeln!(
+                svg,
+                r#"<text x="{x}" y="{}" font-size="16" font-weight="bold" text-anchor="middle" fill="black" stroke="white" stroke-width="3" paint-order="stroke">{}</text>"#,
+                y + 6,
+                pair.kind.to_string().to_uppercase()
+            ),
+        };
+    }
+
+    // Clues outside the grid, stacked away from it
+    let mut stacked: Vec<(Side, usize)> = Vec::new();
+    for clue in table.get_clues() {
+        let key = (clue.side.clone(), clue.index);
+        let n = stacked.iter().filter(|x| **x == key).count();
+        stacked.push(key);
+
+        let along = MARGIN + clue.index * CELL + CELL / 2;
+        let (x, y, anchor) = match clue.side {
+            Side::TOP => (along, MARGIN - 10 - 18 * n, "middle"),
+            Side::BOTTOM => (along, MARGIN + lines * CELL + 22 + 18 * n, "middle"),
+            Side::LEFT => (MARGIN - 8 - 36 * n, along + 6, "end"),
+            Side::RIGHT => (MARGIN + columns * CELL + 8 + 36 * n, along + 6, "start"),
+        };
+        let _ = writeln!(
+            svg,
+            r#"<text x="{x}" y="{y}" font-size="16" text-anchor="{anchor}" fill="black">{}</text>"#,
+            clue.label()
+        );
+    }
+
+    // Values and candidates
+    for square in &table.squares {
+        let (x, y) = center(square.id);
+        match square.value {
+            0 if candidates => {
+                for potential in &square.potentials {
+                    let (dx, dy) = ((potential - 1) % 3, (potential - 1) / 3);
+                    let _ = writeln!(
+                        svg,
+                        r##"<text x="{}" y="{}" font-size="11" text-anchor="middle" fill="#5e5c64">{potential}</text>"##,
+                        x - CELL / 2 + 10 + dx * 15,
+                        y - CELL / 2 + 14 + dy * 15
+                    );
+                }
+            }
+            0 => (),
+            value => {
+                let style = match square.kind {
+                    Some(SetKind::GIVEN) => r#"font-weight="bold" fill="black""#,
+                    _ => r##"fill="#1a5fb4""##,
+                };
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{x}" y="{}" font-size="30" text-anchor="middle" {style}>{value}</text>"#,
+                    y + 11
+                );
+            }
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/*
+ * Top-left corner of the square at (line, column)
+ */
+fn corner(position: (usize, usize)) -> (usize, usize) {
+    (MARGIN + position.1 * CELL, MARGIN + position.0 * CELL)
+}
+
+/*
+ * Position (line, column) of each square on the canvas of all grids
+ */
+fn positions(table: &Table) -> Vec<(usize, usize)> {
+    let mut result: Vec<(usize, usize)> = vec![(0, 0); table.squares.len()];
+    for grid in table.get_grids() {
+        for line in 0..9 {
+            for column in 0..9 {
+                result[grid.get_square_id(line, column)] = (grid.line + line, grid.column + column);
+            }
+        }
+    }
+    result
+}
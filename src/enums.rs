@@ -1,11 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Container {
     ABOX,
     LINE,
     COLUMN,
+    SQUARE,
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SetKind {
+    GIVEN,
     NORMAL,
     GUESS,
 }
@@ -14,4 +19,64 @@ pub enum Progress {
     Solved(String),
     InProgress(i32),
     LimitReached(String),
+    TimedOut(String),
+    Cancelled(String),
+}
+
+pub enum Step {
+    Deduced(Technique),
+    Guessed,
+    RolledBack,
+    Done(Progress),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Rule {
+    ANTIKNIGHT,
+    ANTIKING,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PairKind {
+    WHITE,
+    BLACK,
+    X,
+    V,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PathKind {
+    THERMO,
+    ARROW,
+    PALINDROME,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ClueKind {
+    SANDWICH,
+    LITTLEKILLER,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Side {
+    TOP,
+    BOTTOM,
+    LEFT,
+    RIGHT,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Technique {
+    LINEONELEFT,
+    COLUMNONELEFT,
+    BOXONELEFT,
+    ONLYONEPOSSIBLE,
+    BOX,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HintLevel {
+    CONTAINER,
+    TECHNIQUE,
+    ANSWER,
 }
@@ -0,0 +1,211 @@
+/*
+ * C interface, for embedding the solver
+ *
+ * A table is created from 81 squares, solved, read back and freed through an
+ * opaque pointer. Functions do not panic across the boundary, an error is a
+ * NULL table or a negative return. The header, include/singlenum.h, is
+ * generated from this file with the header feature.
+ *
+ */
+use crate::cancel::CancelToken;
+use crate::components::table::core::Table;
+use crate::enums::{Progress, Technique};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Duration;
+
+/// A puzzle being solved, owned by the caller until freed.
+pub struct SinglenumTable {
+    table: Table,
+}
+
+/// Outcome of solving, an error is negative.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SinglenumOutcome {
+    SOLVED = 0,
+    LIMITREACHED = 1,
+    TIMEDOUT = 2,
+    ERROR = -1,
+}
+
+/// Stats of solving so far.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SinglenumStats {
+    pub iterations: i32,
+    pub snapshots: usize,
+    pub rollbacks: usize,
+}
+
+/// Next logical step: the square, from 0 top-left, to set to value.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SinglenumHint {
+    pub square: usize,
+    pub line: usize,
+    pub column: usize,
+    pub value: usize,
+    /// 0 line, 1 column or 2 box with one value left, 3 only one value
+    /// possible in the square, 4 only one square in the box for the value.
+    pub technique: u8,
+}
+
+/// Create a classic table from 81 squares, 0 for an empty square.
+///
+/// `attempts` limits the steps of solving the table, over all `singlenum_solve`
+/// calls, it can not be changed once the table is created.
+///
+/// Returns NULL when there are not 81 squares of 0-9, or the givens conflict.
+///
+/// # Safety
+///
+/// `squares` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn singlenum_table_new(
+    squares: *const u8,
+    len: usize,
+    attempts: i32,
+) -> *mut SinglenumTable {
+    if squares.is_null() || len != 81 {
+        return std::ptr::null_mut();
+    }
+    let squares = std::slice::from_raw_parts(squares, len);
+    if squares.iter().any(|x| *x > 9) {
+        return std::ptr::null_mut();
+    }
+    let layout: Vec<usize> = squares.iter().map(|x| *x as usize).collect();
+    let result = catch_unwind(|| {
+        let mut table = Table::new_multi([((0, 0), layout)].to_vec(), attempts).ok()?;
+        match table.validate() {
+            Ok(true) => Some(table),
+            _ => None,
+        }
+    });
+    match result {
+        Ok(Some(table)) => Box::into_raw(Box::new(SinglenumTable { table })),
+        _ => std::ptr::null_mut(),
+    }
+}
+
+/// Solve the table, for at most `timeout_ms` milliseconds, 0 for no limit.
+///
+/// The limit of attempts is the one given to `singlenum_table_new`.
+///
+/// # Safety
+///
+/// `table` must come from `singlenum_table_new` and not be freed.
+#[no_mangle]
+pub unsafe extern "C" fn singlenum_solve(
+    table: *mut SinglenumTable,
+    timeout_ms: u64,
+) -> SinglenumOutcome {
+    let Some(table) = table.as_mut() else {
+        return SinglenumOutcome::ERROR;
+    };
+    let token = match timeout_ms {
+        0 => CancelToken::new(),
+        timeout => CancelToken::new().with_timeout(Duration::from_millis(timeout)),
+    };
+    match catch_unwind(AssertUnwindSafe(|| table.table.solve_with(&token))) {
+        Ok(Ok(Progress::Solved(_))) => SinglenumOutcome::SOLVED,
+        Ok(Ok(Progress::LimitReached(_))) => SinglenumOutcome::LIMITREACHED,
+        Ok(Ok(Progress::TimedOut(_))) => SinglenumOutcome::TIMEDOUT,
+        _ => SinglenumOutcome::ERROR,
+    }
+}
+
+/// Copy the 81 squares to `out`, 0 for an empty square.
+///
+/// Returns the number of squares written, or -1 when `len` is less than 81.
+///
+/// # Safety
+///
+/// `table` must come from `singlenum_table_new` and not be freed, `out` must
+/// point to `len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn singlenum_grid(
+    table: *const SinglenumTable,
+    out: *mut u8,
+    len: usize,
+) -> i32 {
+    let Some(table) = table.as_ref() else {
+        return -1;
+    };
+    let squares = &table.table.squares;
+    if out.is_null() || len < squares.len() {
+        return -1;
+    }
+    let out = std::slice::from_raw_parts_mut(out, squares.len());
+    for (square, value) in squares.iter().zip(out.iter_mut()) {
+        *value = square.value as u8;
+    }
+    squares.len() as i32
+}
+
+/// Stats of solving so far, all 0 for a NULL table.
+///
+/// # Safety
+///
+/// `table` must come from `singlenum_table_new` and not be freed.
+#[no_mangle]
+pub unsafe extern "C" fn singlenum_stats(table: *const SinglenumTable) -> SinglenumStats {
+    match table.as_ref() {
+        Some(table) => SinglenumStats {
+            iterations: table.table.get_iterations(),
+            snapshots: table.table.get_snapshots_taken(),
+            rollbacks: table.table.get_snapshot_rollbacks(),
+        },
+        None => SinglenumStats::default(),
+    }
+}
+
+/// Next logical step, without changing the table.
+///
+/// Returns 1 with the step written to `out`, 0 when no logical step is left
+/// or -1 on error.
+///
+/// # Safety
+///
+/// `table` must come from `singlenum_table_new` and not be freed, `out` must
+/// point to a writable `SinglenumHint`.
+#[no_mangle]
+pub unsafe extern "C" fn singlenum_hint(
+    table: *const SinglenumTable,
+    out: *mut SinglenumHint,
+) -> i32 {
+    let (Some(table), Some(out)) = (table.as_ref(), out.as_mut()) else {
+        return -1;
+    };
+    match catch_unwind(AssertUnwindSafe(|| table.table.hint())) {
+        Ok(Ok(Some(hint))) => {
+            *out = SinglenumHint {
+                square: hint.square_id,
+                line: hint.line,
+                column: hint.column,
+                value: hint.value,
+                technique: match hint.technique {
+                    Technique::LINEONELEFT => 0,
+                    Technique::COLUMNONELEFT => 1,
+                    Technique::BOXONELEFT => 2,
+                    Technique::ONLYONEPOSSIBLE => 3,
+                    Technique::BOX => 4,
+                },
+            };
+            1
+        }
+        Ok(Ok(None)) => 0,
+        _ => -1,
+    }
+}
+
+/// Free the table, NULL is ignored.
+///
+/// # Safety
+///
+/// `table` must come from `singlenum_table_new` and not be freed already.
+#[no_mangle]
+pub unsafe extern "C" fn singlenum_table_free(table: *mut SinglenumTable) {
+    if !table.is_null() {
+        drop(Box::from_raw(table));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::components::table::core::Table;
+use crate::puzzle::load;
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use camino::Utf8PathBuf;
+use std::fmt;
+
+/*
+ * A square where the solver's result differs from the known solution
+ *
+ * Lines and columns are numbered from 0, as for Hint, and from 1 when
+ * described. An empty square is 0.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub square_id: usize,
+    pub line: usize,
+    pub column: usize,
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let actual = match self.actual {
+            0 => String::from("empty"),
+            value => value.to_string(),
+        };
+        write!(
+            f,
+            "Line {}, column {}: expected {}, got {actual}",
+            self.line + 1,
+            self.column + 1,
+            self.expected
+        )
+    }
+}
+
+/*
+ * Known solution of a puzzle, the sibling file with .solution before the
+ * extension, e.g. 01.solution.json for 01.json
+ *
+ */
+pub fn solution_file(puzzle: &Utf8PathBuf) -> Utf8PathBuf {
+    let name = match (puzzle.file_stem(), puzzle.extension()) {
+        (Some(stem), Some(extension)) => format!("{stem}.solution.{extension}"),
+        (Some(stem), None) => format!("{stem}.solution"),
+        _ => String::from("solution"),
+    };
+    puzzle.with_file_name(name)
+}
+
+pub fn is_solution_file(file: &Utf8PathBuf) -> bool {
+    file.file_stem()
+        .map(|x| x == "solution" || x.ends_with(".solution"))
+        .unwrap_or(false)
+}
+
+/*
+ * Squares of the known solution, None when the puzzle has none
+ *
+ */
+pub fn expected(puzzle: &Utf8PathBuf) -> AnyhowResult<Option<Vec<usize>>> {
+    let file = solution_file(puzzle);
+    if !file.exists() {
+        return Ok(None);
+    }
+    let table = load(&file, 0).map_err(|e| anyhow!("Solution: {file:?} {e}"))?;
+    Ok(Some(table.squares.iter().map(|x| x.value).collect()))
+}
+
+/*
+ * Squares of the table that differ from the solution, in order
+ *
+ */
+pub fn diff(table: &Table, solution: &[usize]) -> AnyhowResult<Vec<Mismatch>> {
+    if solution.len() != table.squares.len() {
+        return Err(anyhow!(
+            "A solution has {} squares, not {}",
+            table.squares.len(),
+            solution.len()
+        ));
+    }
+    Ok(table
+        .squares
+        .iter()
+        .filter(|x| x.value != solution[x.id])
+        .map(|x| Mismatch {
+            square_id: x.id,
+            line: x.line_ids[0] % 9,
+            column: x.column_ids[0] % 9,
+            expected: solution[x.id],
+            actual: x.value,
+        })
+        .collect())
+}
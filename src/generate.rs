@@ -0,0 +1,76 @@
+use crate::cancel::CancelToken;
+use crate::components::table::core::Table;
+use crate::enums::Progress;
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use rand::seq::SliceRandom;
+
+/*
+ * Generate a classic puzzle that solves without guessing
+ *
+ * A full grid is solved from an empty table, then givens are removed in
+ * random order as long as the engine alone still solves the puzzle. The
+ * engine only deduces, so such a puzzle has a single solution. Removing stops
+ * at givens, fewer may not be possible.
+ *
+ */
+pub fn generate(givens: usize, attempts: i32) -> AnyhowResult<Vec<usize>> {
+    generate_with(givens, attempts, &CancelToken::new())
+}
+
+/*
+ * Generate a puzzle until the token is cancelled or times out
+ *
+ * Removing givens stops early when the token stops, the puzzle has more
+ * givens then. Stopped before a full grid is found is an error.
+ *
+ */
+pub fn generate_with(
+    givens: usize,
+    attempts: i32,
+    token: &CancelToken,
+) -> AnyhowResult<Vec<usize>> {
+    let mut puzzle = solution(attempts, token)?;
+    let mut order: Vec<usize> = (0..puzzle.len()).collect();
+    order.shuffle(&mut rand::thread_rng());
+
+    for id in order {
+        if puzzle.iter().filter(|x| **x != 0).count() <= givens || stopped(token) {
+            break;
+        }
+        let value = puzzle[id];
+        puzzle[id] = 0;
+        if !logical(&puzzle, attempts, token)? {
+            puzzle[id] = value;
+        }
+    }
+    Ok(puzzle)
+}
+
+/*
+ * A full grid, random from the guesses of solving an empty table
+ *
+ */
+fn solution(attempts: i32, token: &CancelToken) -> AnyhowResult<Vec<usize>> {
+    for _ in 0..10 {
+        let mut table = Table::new([0; 81].to_vec(), attempts)?;
+        match table.solve_with(token)? {
+            Progress::Solved(_) => return Ok(table.squares.iter().map(|x| x.value).collect()),
+            Progress::TimedOut(_) | Progress::Cancelled(_) => {
+                return Err(anyhow!("Stopped before a full grid was found"))
+            }
+            _ => (),
+        }
+    }
+    Err(anyhow!("Unable to fill a grid in {attempts} attempts"))
+}
+
+fn logical(puzzle: &[usize], attempts: i32, token: &CancelToken) -> AnyhowResult<bool> {
+    let mut table = Table::new(puzzle.to_vec(), attempts)?;
+    let solved = matches!(table.solve_with(token)?, Progress::Solved(_));
+    Ok(solved && table.get_snapshots_taken() == 0)
+}
+
+fn stopped(token: &CancelToken) -> bool {
+    token.timed_out() || token.is_cancelled()
+}
@@ -1,14 +1,41 @@
 pub mod args;
+pub mod bench;
+pub mod booklet;
+pub mod cancel;
+pub mod checkpoint;
 pub mod enums;
+pub mod ffi;
+pub mod fixture;
+pub mod generate;
+pub mod play;
+pub mod puzzle;
+pub mod record;
+pub mod serve;
+pub mod verify;
+pub mod worker;
+
+pub use verify::verify;
 
 pub mod components {
     pub mod abox;
+    pub mod cage;
+    pub mod clue;
     pub mod column;
+    pub mod constraint;
+    pub mod grid;
+    pub mod hint;
     pub mod line;
+    pub mod pair;
+    pub mod path;
+    pub mod region;
     pub mod square;
     pub mod table {
+        pub mod animate;
         pub mod core;
         pub mod draw;
+        pub mod render;
+        pub mod rules;
+        pub mod svg;
     }
 }
 
@@ -25,84 +25,710 @@
  *
  */
 
+use anyhow::anyhow;
 use anyhow::Result as AnyhowResult;
 use camino::Utf8PathBuf;
-use clap::Parser;
-use singlenum::args::Arguments;
-use singlenum::components::table;
-use singlenum::components::table::draw::draw_table;
+use clap::{CommandFactory, Parser};
+use signal_hook::consts::SIGINT;
+use singlenum::args::{Arguments, Board, Command, Format, Output, Render, Solve, Style};
+use singlenum::bench::{compare, measure, Baseline};
+use singlenum::booklet::{booklet, difficulty};
+use singlenum::cancel::CancelToken;
+use singlenum::checkpoint::{Checkpoint, Current};
+use singlenum::components::table::animate::animate;
+use singlenum::components::table::core::Table;
+use singlenum::components::table::render::{Accessible, Ascii, Compact, Renderer, Svg, Unicode};
 use singlenum::enums::Progress;
-use std::fs::File;
-use std::io::BufReader;
+use singlenum::fixture::{diff, expected, is_solution_file};
+use singlenum::generate::generate;
+use singlenum::play::play;
+use singlenum::puzzle::{line, load, parse};
+use singlenum::record::{grid, Record};
+use singlenum::serve::{Limits, Service};
+use singlenum::verify;
+use singlenum::worker::worker;
+use std::io::{stdin, stdout, IsTerminal};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 use walkdir::WalkDir;
 
 fn main() -> AnyhowResult<()> {
     env_logger::init();
     let args: Arguments = Arguments::parse();
 
-    if let Some(file) = args.group.file {
-        if !file.exists() {
-            println!("File: {file:?} does not exist!");
-        } else {
-            runner(file, args.attempts, args.novisual, args.just_draw)?;
-        }
-    } else if let Some(path) = args.group.path {
-        // Find all puzzle files!
-        for entry in WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
-            if let Some(extension) = entry.path().extension() {
-                if extension.to_str().unwrap() == "json" {
-                    let a: Utf8PathBuf = Utf8PathBuf::from(entry.path().to_str().unwrap());
-                    runner(a, args.attempts, args.novisual, args.just_draw)?;
+    match &args.command {
+        Command::Solve(solve) => solve_all(&args, solve),
+        Command::Draw { path, board } => {
+            for file in puzzle_files(path)? {
+                draw(&file, &args, board)?;
+            }
+            Ok(())
+        }
+        Command::Validate { path, solution } => match solution {
+            Some(solution) => verify_solution(path, solution, args.attempts),
+            None => validate(path, args.attempts),
+        },
+        Command::Generate { givens, count } => {
+            for _ in 0..*count {
+                let puzzle = generate(*givens, args.attempts)?;
+                println!("{}", serde_json::to_string(&puzzle)?);
+            }
+            Ok(())
+        }
+        Command::Grade { path } => grade(path, &args),
+        Command::Bench {
+            path,
+            runs,
+            baseline,
+            save,
+            threshold,
+        } => bench(path, *runs, baseline, save, *threshold, &args),
+        Command::Convert { file, to } => convert(file, to, args.attempts),
+        Command::Completions { shell } => {
+            clap_complete::generate(
+                *shell,
+                &mut Arguments::command(),
+                "singlenum",
+                &mut stdout(),
+            );
+            Ok(())
+        }
+        Command::Serve {
+            bind,
+            threads,
+            max_body,
+        } => {
+            let limits = Limits {
+                max_body: *max_body,
+                timeout: args.timeout.unwrap_or(Limits::default().timeout),
+                attempts: args.attempts,
+            };
+            let service = Service::bind(bind, *threads, limits)?;
+            eprintln!("Listening on http://{}", service.address()?);
+            service.join();
+            Ok(())
+        }
+        Command::Worker => {
+            let limits = Limits {
+                timeout: args.timeout.unwrap_or(Limits::default().timeout),
+                attempts: args.attempts,
+                ..Limits::default()
+            };
+            worker(stdin().lock(), stdout().lock(), limits)
+        }
+        Command::Play { file } => play(load(file, args.attempts)?),
+        Command::Booklet {
+            path,
+            per_page,
+            output,
+        } => {
+            let mut puzzles: Vec<(String, Table)> = Vec::new();
+            for file in puzzle_files(path)? {
+                let title = file.strip_prefix(path).unwrap_or(&file).with_extension("");
+                puzzles.push((title.to_string(), load(&file, args.attempts)?));
+            }
+            let timeout = args.timeout.unwrap_or(singlenum::booklet::TIMEOUT);
+            std::fs::write(output, booklet(puzzles, *per_page, timeout)?)?;
+            println!("Booklet written to {output}");
+            Ok(())
+        }
+    }
+}
+
+/*
+ * Solve all puzzles in path
+ *
+ */
+fn solve_all(args: &Arguments, solve: &Solve) -> AnyhowResult<()> {
+    // With a checkpoint, SIGINT stops the run between steps to write it. A
+    // second SIGINT, once the flag is set, exits at once.
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let token = CancelToken::from_flag(Arc::clone(&interrupted));
+    let mut checkpoint = match &solve.checkpoint {
+        Some(file) => {
+            signal_hook::flag::register_conditional_shutdown(
+                SIGINT,
+                130,
+                Arc::clone(&interrupted),
+            )?;
+            signal_hook::flag::register(SIGINT, interrupted)?;
+            Some(Checkpoint::load(file)?)
+        }
+        None => None,
+    };
+
+    let (sources, output): (Box<dyn Iterator<Item = Source>>, Output) = match solve.path.as_str() {
+        "-" => (
+            stdin_puzzles()?,
+            solve.output.clone().unwrap_or(Output::NDJSON),
+        ),
+        _ => (
+            Box::new(puzzle_files(&solve.path)?.into_iter().map(Source::File)),
+            solve.output.clone().unwrap_or(Output::BOARD),
+        ),
+    };
+    if solve.path == "-" && solve.board.render.is_some() {
+        return Err(anyhow!("Rendering to files needs puzzle files, not stdin"));
+    }
+    if solve.animate && output != Output::BOARD {
+        return Err(anyhow!("Animating draws boards, not one line per puzzle"));
+    }
+    if solve.animate && !stdout().is_terminal() {
+        return Err(anyhow!(
+            "Animating redraws the board in place, stdout must be a terminal"
+        ));
+    }
+
+    let (mut failed, mut errors) = (0, 0);
+    for source in sources {
+        let name = source.name();
+        let finished = match token.is_cancelled() {
+            true => None,
+            false => match runner(source, args, solve, &output, &mut checkpoint, &token) {
+                // One line per puzzle carries on past a puzzle that fails
+                Err(e) if output != Output::BOARD => {
+                    errors += 1;
+                    match output {
+                        Output::LINE => println!("{name} error {e}"),
+                        _ => println!("{}", error_record(&name, &e)),
+                    }
+                    Some(true)
                 }
+                result => result?,
+            },
+        };
+        if finished == Some(false) {
+            failed += 1;
+        }
+        if let (Some(file), Some(checkpoint)) = (&solve.checkpoint, &checkpoint) {
+            checkpoint.save(file)?;
+            if finished.is_none() {
+                eprintln!("Interrupted, checkpoint written to {file}");
+                std::process::exit(130);
             }
         }
     }
-    Ok(())
+    let mut summary: Vec<String> = Vec::new();
+    if errors > 0 {
+        summary.push(format!("{errors} puzzle(s) could not be solved"));
+    }
+    if failed > 0 {
+        summary.push(format!("{failed} puzzle(s) do not match their solution"));
+    }
+    match summary.is_empty() {
+        true => Ok(()),
+        false => Err(anyhow!("{}", summary.join(", "))),
+    }
 }
 
-fn runner(
-    puzzle: Utf8PathBuf,
-    attempts: i32,
-    novisual: bool,
-    just_draw: bool,
-) -> AnyhowResult<bool> {
-    let file = File::open(&puzzle)?;
-    let reader = BufReader::new(file);
-    let layout: Vec<usize> = serde_json::from_reader(reader)?;
+fn error_record(puzzle: &str, error: &anyhow::Error) -> serde_json::Value {
+    serde_json::json!({ "puzzle": puzzle, "outcome": "error", "error": error.to_string() })
+}
 
-    let mut table = table::core::Table::new(layout, attempts);
+/*
+ * A puzzle to solve, from a file or from stdin
+ *
+ */
+enum Source {
+    File(Utf8PathBuf),
+    Json(String, serde_json::Value),
+    Line(String, String),
+    Invalid(String, String),
+}
 
-    println!("{}", &puzzle);
-    draw_table(&table, novisual);
+impl Source {
+    fn name(&self) -> String {
+        match self {
+            Source::File(file) => file.to_string(),
+            Source::Json(name, _) | Source::Line(name, _) | Source::Invalid(name, _) => {
+                name.clone()
+            }
+        }
+    }
 
-    if just_draw {
-        return Ok(true);
+    fn load(&self, attempts: i32) -> AnyhowResult<Table> {
+        match self {
+            Source::File(file) => load(file, attempts),
+            Source::Json(name, content) => parse(name, content, attempts),
+            Source::Line(_, content) => {
+                Table::new_multi([((0, 0), line(content)?)].to_vec(), attempts)
+            }
+            Source::Invalid(_, error) => Err(anyhow!("{error}")),
+        }
     }
 
-    loop {
-        match table.complete() {
-            Progress::Solved(msg) => {
-                draw_table(&table, novisual);
-                println!("Puzzle solved {msg}");
-                return Ok(true);
+    /*
+     * Puzzles of a line or document of JSON, a list of puzzles or a puzzle
+     *
+     */
+    fn json(name: impl Fn(usize) -> String, content: &str) -> Vec<Source> {
+        match serde_json::from_str::<serde_json::Value>(content) {
+            Ok(serde_json::Value::Array(list)) if !list.iter().any(|x| x.is_number()) => list
+                .into_iter()
+                .enumerate()
+                .map(|(i, x)| Source::Json(name(i), x))
+                .collect(),
+            Ok(value) => vec![Source::Json(name(0), value)],
+            Err(e) => vec![Source::Invalid(name(0), format!("Invalid JSON: {e}"))],
+        }
+    }
+}
+
+/*
+ * Read puzzles from stdin
+ *
+ * Either a single JSON puzzle, a JSON list of puzzles, or one puzzle per line:
+ * JSON or a line of 81 squares. Empty lines and lines starting with # are
+ * skipped. Puzzles per line are read as they come, JSON over several lines is
+ * read to the end first.
+ *
+ */
+fn stdin_puzzles() -> AnyhowResult<Box<dyn Iterator<Item = Source>>> {
+    let mut lines = stdin().lines().enumerate();
+    let name = |i: usize| format!("stdin:{}", i + 1);
+
+    let mut first = None;
+    for (i, entry) in lines.by_ref() {
+        let entry = entry?;
+        if !skip(&entry) {
+            first = Some((i, entry));
+            break;
+        }
+    }
+    let Some((i, first)) = first else {
+        return Ok(Box::new(std::iter::empty()));
+    };
+
+    // A document of JSON, when the first line is not JSON of its own
+    let first = first.trim();
+    if first.starts_with(['[', '{']) && serde_json::from_str::<serde_json::Value>(first).is_err() {
+        let mut content = String::from(first);
+        for (_, entry) in lines {
+            content.push('\n');
+            content.push_str(&entry?);
+        }
+        return Ok(Box::new(Source::json(name, &content).into_iter()));
+    }
+
+    let first = std::iter::once((i, Ok(String::from(first))));
+    Ok(Box::new(
+        first
+            .chain(lines)
+            .map_while(|(i, entry)| match entry {
+                Ok(entry) => Some((i, entry)),
+                Err(e) => {
+                    log::warn!("Unable to read stdin: {e}");
+                    None
+                }
+            })
+            .filter(|(_, entry)| !skip(entry))
+            .flat_map(move |(i, entry)| {
+                let entry = entry.trim();
+                match entry.starts_with(['[', '{']) {
+                    true => Source::json(|x| name(i + x), entry),
+                    false => vec![Source::Line(name(i), String::from(entry))],
+                }
+            }),
+    ))
+}
+
+fn skip(entry: &str) -> bool {
+    let entry = entry.trim();
+    entry.is_empty() || entry.starts_with('#')
+}
+
+/*
+ * Draw a puzzle, without solving
+ *
+ */
+fn draw(puzzle: &Utf8PathBuf, args: &Arguments, board: &Board) -> AnyhowResult<()> {
+    let mut table = load(puzzle, args.attempts)?;
+    if board.candidates {
+        table.update()?;
+    }
+
+    println!("{puzzle}");
+    show(&table, args, board);
+    if let Some(file) = render(&table, puzzle, "start", board)? {
+        println!("Rendered {file}");
+    }
+    Ok(())
+}
+
+/*
+ * Check that puzzles load and that their givens do not conflict
+ *
+ */
+fn validate(path: &Utf8PathBuf, attempts: i32) -> AnyhowResult<()> {
+    let mut invalid = 0;
+    for file in puzzle_files(path)? {
+        let result = load(&file, attempts).and_then(|mut table| match table.validate()? {
+            true => Ok(()),
+            false => Err(anyhow!("givens conflict")),
+        });
+        match result {
+            Ok(()) => println!("{file}: valid"),
+            Err(e) => {
+                invalid += 1;
+                println!("{file}: invalid, {e}");
             }
-            Progress::LimitReached(msg) => {
-                draw_table(&table, novisual);
-                println!("Unable to solve puzzle {msg}");
-                return Ok(false);
+        }
+    }
+    match invalid {
+        0 => Ok(()),
+        n => Err(anyhow!("{n} invalid puzzle(s)")),
+    }
+}
+
+/*
+ * Verify a completed grid as the solution of a puzzle, listing what is wrong
+ *
+ */
+fn verify_solution(file: &Utf8PathBuf, solution: &Utf8PathBuf, attempts: i32) -> AnyhowResult<()> {
+    let puzzle = load(file, attempts)?;
+    let squares: Vec<usize> = load(solution, attempts)?
+        .squares
+        .iter()
+        .map(|x| x.value)
+        .collect();
+
+    let violations = verify(&puzzle, &squares)?;
+    if violations.is_empty() {
+        println!("{solution}: solves {file}");
+        return Ok(());
+    }
+    println!("{solution}: does not solve {file}");
+    for violation in &violations {
+        println!("  {violation}");
+    }
+    Err(anyhow!("{} violation(s)", violations.len()))
+}
+
+/*
+ * Grade puzzles by how the solver gets along
+ *
+ */
+fn grade(path: &Utf8PathBuf, args: &Arguments) -> AnyhowResult<()> {
+    for file in puzzle_files(path)? {
+        let mut table = load(&file, args.attempts)?;
+        let token = match args.timeout {
+            Some(timeout) => CancelToken::new().with_timeout(timeout),
+            None => CancelToken::new(),
+        };
+        let (solved, msg) = match table.solve_with(&token)? {
+            Progress::Solved(msg) => (true, msg),
+            Progress::LimitReached(msg) | Progress::TimedOut(msg) | Progress::Cancelled(msg) => {
+                (false, msg)
             }
-            Progress::InProgress(iteration) => log::debug!("[iteration] {iteration}"),
+            Progress::InProgress(_) => unreachable!(),
         };
+        println!("{file}: {} {msg}", difficulty(&table, solved));
+    }
+    Ok(())
+}
 
-        // Update line, column, box, and finally squares. Then run Engine to set squares
-        table.update()?;
-        if table.engine()? {
+/*
+ * Measure each puzzle, compare with the baseline and save the results
+ *
+ * The baseline is read before saving, so both can be the same file to
+ * compare with the last run and then update it.
+ *
+ */
+fn bench(
+    path: &Utf8PathBuf,
+    runs: usize,
+    baseline: &Option<Utf8PathBuf>,
+    save: &Option<Utf8PathBuf>,
+    threshold: f64,
+    args: &Arguments,
+) -> AnyhowResult<()> {
+    // A baseline that does not exist yet is written by this run
+    let previous = match baseline {
+        Some(file) if file.exists() => {
+            Some(Baseline::load(file).map_err(|e| anyhow!("Baseline: {file:?} {e}"))?)
+        }
+        _ => None,
+    };
+
+    let mut current = Baseline::default();
+    for file in puzzle_files(path)? {
+        let table = load(&file, args.attempts)?;
+        let measure = measure(file.as_str(), &table, runs, args.timeout)?;
+        println!("{measure}");
+        current.measures.push(measure);
+    }
+
+    let first = baseline
+        .as_ref()
+        .filter(|x| previous.is_none() && Some(*x) != save.as_ref());
+    for file in save.iter().chain(first) {
+        current.save(file)?;
+        println!("Baseline written to {file}");
+    }
+    let Some(previous) = previous else {
+        return Ok(());
+    };
+    let regressions = compare(&previous, &current, threshold);
+    if regressions.is_empty() {
+        println!("No regressions beyond {threshold}%");
+        return Ok(());
+    }
+    for regression in &regressions {
+        println!("Regression {regression}");
+    }
+    Err(anyhow!("{} regression(s)", regressions.len()))
+}
+
+/*
+ * Convert a classic puzzle, to a JSON list or a line of digits with . empty
+ *
+ */
+fn convert(file: &Utf8PathBuf, to: &Format, attempts: i32) -> AnyhowResult<()> {
+    let table = load(file, attempts)?;
+    if !table.is_classic() {
+        return Err(anyhow!("Only classic puzzles can be converted: {file:?}"));
+    }
+
+    match to {
+        Format::JSON => {
+            let values: Vec<usize> = table.squares.iter().map(|x| x.value).collect();
+            println!("{}", serde_json::to_string(&values)?);
+        }
+        Format::LINE => println!("{}", grid(&table)),
+    }
+    Ok(())
+}
+
+/*
+ * Find all puzzle files!
+ *
+ * A file is a puzzle of its own, in a directory all JSON files are puzzles.
+ *
+ */
+fn puzzle_files(path: &Utf8PathBuf) -> AnyhowResult<Vec<Utf8PathBuf>> {
+    if !path.exists() {
+        return Err(anyhow!("Path: {path:?} does not exist"));
+    }
+    if path.is_file() {
+        return Ok([path.clone()].to_vec());
+    }
+
+    let mut files: Vec<Utf8PathBuf> = Vec::new();
+    for entry in WalkDir::new(path)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        let Some(file) = entry.path().to_str().map(Utf8PathBuf::from) else {
             continue;
+        };
+        // Known solutions are compared to, not solved
+        if file.extension() == Some("json") && !is_solution_file(&file) {
+            files.push(file);
+        }
+    }
+    Ok(files)
+}
+
+/*
+ * Run a puzzle
+ *
+ * Returns if the result matches the known solution, next to the puzzle file,
+ * true without one and None when interrupted. With a checkpoint, puzzles done
+ * are skipped and the current puzzle is resumed.
+ *
+ */
+fn runner(
+    source: Source,
+    args: &Arguments,
+    solve: &Solve,
+    output: &Output,
+    checkpoint: &mut Option<Checkpoint>,
+    token: &CancelToken,
+) -> AnyhowResult<Option<bool>> {
+    // Other output than boards is one line per puzzle, notes go to stderr
+    let boards = *output == Output::BOARD;
+    let note = |note: &str| match boards {
+        true => println!("{note}"),
+        false => eprintln!("{}: {note}", source.name()),
+    };
+
+    let puzzle = source.name();
+    if boards {
+        println!("{puzzle}");
+    }
+    if let Some(done) = checkpoint.as_ref().and_then(|x| x.get_done(&puzzle)) {
+        note(&format!("Skipped, done in checkpoint {}", done.message));
+        if done.matches == Some(false) {
+            note("Did not match solution");
+        }
+        return Ok(Some(done.matches != Some(false)));
+    }
+
+    let mut table = match checkpoint.as_mut().and_then(|x| x.resume(&puzzle)) {
+        Some(table) => {
+            note("Resumed from checkpoint");
+            table
+        }
+        None => {
+            let mut table = source
+                .load(args.attempts)
+                .map_err(|e| anyhow!("Puzzle: {puzzle:?} {e}"))?;
+            if !table.validate()? {
+                return Err(anyhow!("Puzzle: {puzzle:?} givens conflict"));
+            }
+            table
+        }
+    };
+    let draw = |table: &Table| {
+        if boards && solve.novisual {
+            show(table, args, &solve.board);
+        }
+    };
+
+    // Candidates are only known once the table has been updated
+    if solve.board.candidates {
+        table.update()?;
+    }
+
+    draw(&table);
+    if let Source::File(file) = &source {
+        if let Some(file) = render(&table, file, "start", &solve.board)? {
+            note(&format!("Rendered {file}"));
         }
+    }
 
-        // Guess, first a qualified guess, then a somewhat less qualified (incompetent)
-        if !table.qualified_guess()? && !table.incompetent_guess()? {
-            table.snapshot_rollback()?
+    let token = match args.timeout {
+        Some(timeout) => token.with_timeout(timeout),
+        None => token.clone(),
+    };
+    let progress = match solve.animate {
+        true => {
+            // Squares are highlighted in color on the box drawing board only
+            let highlight = args.style == Style::UNICODE && args.color.enabled();
+            let renderer = renderer(&args.style, args.color.enabled());
+            animate(
+                &mut table,
+                solve.delay,
+                renderer.as_ref(),
+                highlight,
+                &token,
+            )?
+        }
+        false => table.solve_with(&token)?,
+    };
+    let record = Record::new(&puzzle, &table, &progress);
+    let timed_out = matches!(progress, Progress::TimedOut(_));
+    let (solved, msg) = match progress {
+        Progress::Solved(msg) => {
+            draw(&table);
+            if boards {
+                println!("Puzzle solved {msg}");
+            }
+            (true, msg)
+        }
+        Progress::LimitReached(msg) => {
+            draw(&table);
+            if boards {
+                println!("Unable to solve puzzle {msg}");
+            }
+            (false, msg)
+        }
+        Progress::TimedOut(msg) => {
+            draw(&table);
+            if boards {
+                println!("Timed out {msg}");
+            }
+            (false, msg)
+        }
+        Progress::Cancelled(_) => {
+            if let Some(checkpoint) = checkpoint {
+                checkpoint.current = Some(Current { puzzle, table });
+            }
+            return Ok(None);
+        }
+        Progress::InProgress(_) => unreachable!(),
+    };
+    match output {
+        Output::BOARD => (),
+        Output::NDJSON => println!("{}", serde_json::to_string(&record)?),
+        Output::LINE => println!("{}", record.line()),
+    }
+    let mut matches: Option<bool> = None;
+    if let Source::File(file) = &source {
+        if let Some(file) = render(&table, file, "end", &solve.board)? {
+            note(&format!("Rendered {file}"));
+        }
+        if let Some(solution) = expected(file)? {
+            let mismatches = diff(&table, &solution)?;
+            matches = Some(mismatches.is_empty());
+            match mismatches.len() {
+                0 => note("Matches solution"),
+                n => note(&format!("Does not match solution, {n} square(s) differ")),
+            }
+            for mismatch in mismatches {
+                note(&format!("  {mismatch}"));
+            }
         }
     }
+    // A puzzle that timed out is tried again on a rerun, maybe given longer
+    if let (Some(checkpoint), false) = (checkpoint, timed_out) {
+        checkpoint.finish(&puzzle, solved, matches, &msg);
+    }
+    Ok(Some(matches != Some(false)))
+}
+
+/*
+ * Draw the table as the board options and style say
+ *
+ */
+fn show(table: &Table, args: &Arguments, board: &Board) {
+    let renderer = renderer(&args.style, args.color.enabled());
+    match board.candidates {
+        true => print!("{}", renderer.render_candidates(table)),
+        false => print!("{}", renderer.render(table)),
+    }
+}
+
+/*
+ * Renderer for the style of the boards drawn
+ *
+ */
+fn renderer(style: &Style, color: bool) -> Box<dyn Renderer> {
+    match style {
+        Style::UNICODE => Box::new(Unicode { color }),
+        Style::ASCII => Box::new(Ascii),
+        Style::COMPACT => Box::new(Compact),
+        Style::ACCESSIBLE => Box::new(Accessible),
+    }
+}
+
+/*
+ * Render the table to a file next to the puzzle, e.g. 01.start.svg
+ *
+ * The file written, if the board options ask for one.
+ *
+ */
+fn render(
+    table: &Table,
+    puzzle: &Utf8PathBuf,
+    state: &str,
+    board: &Board,
+) -> AnyhowResult<Option<Utf8PathBuf>> {
+    let Some(format) = &board.render else {
+        return Ok(None);
+    };
+
+    let (content, extension) = match format {
+        Render::SVG => (
+            Svg {
+                candidates: board.candidates,
+            }
+            .render(table),
+            "svg",
+        ),
+    };
+    let file = puzzle.with_extension(format!("{state}.{extension}"));
+    std::fs::write(&file, content)?;
+    Ok(Some(file))
 }
@@ -0,0 +1,340 @@
+use crate::components::hint::Hint;
+use crate::components::table::core::Table;
+use crate::components::table::draw::board;
+use crate::enums::{HintLevel, Progress, SetKind};
+use crate::utils::helpers;
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::{Attribute, Color, Print, SetAttribute, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::io::{stdout, Write};
+
+/*
+ * A game of a single grid puzzle
+ *
+ * Entries are the values set by the player, the givens of the puzzle are
+ * kept in the table. Pencil marks are kept per square. Each change is pushed
+ * on the undo stack as (square id, entry, marks) before the change.
+ *
+ */
+pub struct Game {
+    table: Table,
+    solution: Option<Vec<usize>>,
+    pub entries: Vec<usize>,
+    pub marks: Vec<Vec<usize>>,
+    pub cursor: usize,
+    pub pencil: bool,
+    pub wrong: Vec<usize>,
+    pub message: String,
+    hints: usize,
+    undo: Vec<(usize, usize, Vec<usize>)>,
+}
+
+impl Game {
+    pub fn new(table: Table) -> AnyhowResult<Game> {
+        if table.get_grids().len() > 1 {
+            return Err(anyhow!("Play supports a single grid only"));
+        }
+
+        // The solution is used to check entries, some puzzles are too hard
+        let mut solver = table.clone();
+        let solution = match solver.solve()? {
+            Progress::Solved(_) => Some(solver.squares.iter().map(|x| x.value).collect()),
+            _ => None,
+        };
+
+        let count = table.squares.len();
+        Ok(Game {
+            table,
+            solution,
+            entries: vec![0; count],
+            marks: vec![Vec::new(); count],
+            cursor: 0,
+            pencil: false,
+            wrong: Vec::new(),
+            message: String::new(),
+            hints: 0,
+            undo: Vec::new(),
+        })
+    }
+
+    /*
+     * Value of each square, given or entered
+     *
+     */
+    pub fn values(&self) -> Vec<usize> {
+        self.table
+            .squares
+            .iter()
+            .zip(&self.entries)
+            .map(|(square, entry)| square.value.max(*entry))
+            .collect()
+    }
+
+    pub fn given(&self, square_id: usize) -> bool {
+        self.table.squares[square_id].value != 0
+    }
+
+    /*
+     * Move the cursor by (lines, columns), wrapping around the edges
+     *
+     */
+    pub fn move_cursor(&mut self, lines: i32, columns: i32) {
+        let line = (self.cursor as i32 / 9 + lines).rem_euclid(9);
+        let column = (self.cursor as i32 % 9 + columns).rem_euclid(9);
+        self.cursor = (line * 9 + column) as usize;
+    }
+
+    /*
+     * Enter a digit at the cursor, 0 clears the square
+     *
+     * In pencil mode the digit is toggled in the marks of the square instead.
+     *
+     */
+    pub fn enter(&mut self, value: usize) {
+        let id = self.cursor;
+        if self.given(id) {
+            self.message = String::from("Can not change a given square");
+            return;
+        }
+
+        self.undo
+            .push((id, self.entries[id], self.marks[id].clone()));
+        if self.pencil && value != 0 {
+            if !helpers::remove_element(value, &mut self.marks[id]) {
+                self.marks[id].push(value);
+                self.marks[id].sort();
+            }
+        } else {
+            self.entries[id] = value;
+            self.marks[id].clear();
+        }
+        self.wrong.retain(|x| *x != id);
+        self.message.clear();
+        self.hints = 0;
+
+        if self.solution.as_ref() == Some(&self.values()) {
+            self.message = String::from("Solved, well done!");
+        }
+    }
+
+    /*
+     * Undo the last change
+     *
+     */
+    pub fn undo(&mut self) {
+        match self.undo.pop() {
+            Some((id, entry, marks)) => {
+                self.entries[id] = entry;
+                self.marks[id] = marks;
+                self.cursor = id;
+                self.message.clear();
+            }
+            None => self.message = String::from("Nothing to undo"),
+        }
+    }
+
+    /*
+     * Compare entries against the solution
+     *
+     */
+    pub fn check(&mut self) {
+        let Some(solution) = &self.solution else {
+            self.message = String::from("No solution found, unable to check");
+            return;
+        };
+
+        self.wrong = (0..self.entries.len())
+            .filter(|x| self.entries[*x] != 0 && self.entries[*x] != solution[*x])
+            .collect();
+        self.message = match self.wrong.len() {
+            0 => String::from("No mistakes"),
+            n => format!("{n} mistake(s)"),
+        };
+    }
+
+    /*
+     * Ask the engine for the next logical step
+     *
+     * The hint comes from a copy of the table with the entries set, none when
+     * they are wrong or conflict. Asking again, without entering anything in
+     * between, gives more detail until the cursor is moved to the square.
+     * Nothing is entered.
+     *
+     */
+    pub fn hint(&mut self) -> AnyhowResult<Option<Hint>> {
+        self.check();
+        if !self.wrong.is_empty() {
+            self.message = format!("Fix {} mistake(s) first", self.wrong.len());
+            return Ok(None);
+        }
+
+        let mut table = self.table.clone();
+        for (id, entry) in self.entries.iter().enumerate() {
+            if *entry != 0 {
+                table.set_square(id, *entry, SetKind::NORMAL)?;
+            }
+        }
+
+        // Without a solution mistakes are only found when entries conflict
+        if !table.validate()? {
+            self.message = String::from("Entries conflict, fix them first");
+            return Ok(None);
+        }
+
+        let Some(hint) = table.hint()? else {
+            self.message = String::from("No logical step left");
+            return Ok(None);
+        };
+
+        let level = match self.hints {
+            0 => HintLevel::CONTAINER,
+            1 => HintLevel::TECHNIQUE,
+            _ => {
+                self.cursor = hint.square_id;
+                HintLevel::ANSWER
+            }
+        };
+        self.hints += 1;
+        self.message = format!("Hint: {}", hint.describe(&level));
+        Ok(Some(hint))
+    }
+}
+
+/*
+ * Play a puzzle in the terminal
+ *
+ *   arrows       move
+ *   1-9          enter digit (or toggle pencil mark)
+ *   0, del       clear square
+ *   p            toggle pencil mode
+ *   u            undo
+ *   h            hint, again for more detail
+ *   c            check
+ *   q, esc       quit
+ *
+ */
+pub fn play(table: Table) -> AnyhowResult<()> {
+    let mut game = Game::new(table)?;
+
+    let _screen = Screen::enter()?;
+    run(&mut game)
+}
+
+/*
+ * Raw mode and the alternate screen, left again when dropped
+ *
+ * Dropped on any way out of play, an error or a panic as well, so the
+ * terminal is not left broken.
+ *
+ */
+struct Screen;
+
+impl Screen {
+    fn enter() -> AnyhowResult<Screen> {
+        terminal::enable_raw_mode()?;
+        let screen = Screen;
+        execute!(stdout(), EnterAlternateScreen, Hide)?;
+        Ok(screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn run(game: &mut Game) -> AnyhowResult<()> {
+    loop {
+        render(game)?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Up => game.move_cursor(-1, 0),
+            KeyCode::Down => game.move_cursor(1, 0),
+            KeyCode::Left => game.move_cursor(0, -1),
+            KeyCode::Right => game.move_cursor(0, 1),
+            KeyCode::Char(c @ '0'..='9') => game.enter(c as usize - '0' as usize),
+            KeyCode::Delete | KeyCode::Backspace => game.enter(0),
+            KeyCode::Char('p') => game.pencil = !game.pencil,
+            KeyCode::Char('u') => game.undo(),
+            KeyCode::Char('h') => {
+                game.hint()?;
+            }
+            KeyCode::Char('c') => game.check(),
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            _ => (),
+        }
+    }
+}
+
+/*
+ * Draw the game
+ *
+ * The frame is drawn as by draw_table, then each square is drawn again on
+ * top of it with its style. Empty squares with pencil marks show a dot.
+ *
+ */
+fn render(game: &Game) -> AnyhowResult<()> {
+    let mut out = stdout();
+    queue!(out, Clear(ClearType::All))?;
+
+    let values = game.values();
+    for (i, row) in board(&game.table, &values, false).iter().enumerate() {
+        queue!(out, MoveTo(0, i as u16), Print(row))?;
+    }
+
+    for (id, value) in values.iter().enumerate() {
+        let text = match (*value, game.marks[id].is_empty()) {
+            (0, true) => String::from("   "),
+            (0, false) => String::from(" · "),
+            (value, _) => format!(" {value} "),
+        };
+
+        let x = (4 * (id % 9) + 1) as u16;
+        let y = (2 * (id / 9) + 1) as u16;
+        queue!(out, MoveTo(x, y))?;
+        if game.given(id) {
+            queue!(out, SetAttribute(Attribute::Bold))?;
+        } else if game.wrong.contains(&id) {
+            queue!(out, SetForegroundColor(Color::Red))?;
+        } else if *value != 0 {
+            queue!(out, SetForegroundColor(Color::Blue))?;
+        }
+        if id == game.cursor {
+            queue!(out, SetAttribute(Attribute::Reverse))?;
+        }
+        queue!(out, Print(text), SetAttribute(Attribute::Reset))?;
+    }
+
+    let marks: Vec<String> = game.marks[game.cursor]
+        .iter()
+        .map(|x| x.to_string())
+        .collect();
+    let status = [
+        format!(
+            "Mode: {}   Marks: {}",
+            if game.pencil { "pencil" } else { "digit" },
+            marks.join(" ")
+        ),
+        game.message.clone(),
+        String::from("arrows move, 1-9 enter, 0 clear, p pencil, u undo, h hint, c check, q quit"),
+    ];
+    for (i, line) in status.iter().enumerate() {
+        queue!(out, MoveTo(0, (20 + i) as u16), Print(line))?;
+    }
+
+    out.flush()?;
+    Ok(())
+}
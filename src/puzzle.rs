@@ -0,0 +1,198 @@
+use crate::components::cage::Cage;
+use crate::components::clue::Clue;
+use crate::components::constraint::{diagonal, Constraint};
+use crate::components::pair::Pair;
+use crate::components::path::Path;
+use crate::components::table::core::Table;
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use camino::Utf8PathBuf;
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::io::BufReader;
+
+/*
+ * Load puzzle file
+ *
+ * A puzzle is either a plain list of squares, a classic sudoku, or an object
+ * with the list of squares as 'layout' together with an optional list of
+ * variant 'rules'. Each rule is an object with a 'type' and the fields of that
+ * type.
+ *
+ *   {
+ *     "layout": [0, 0, 3, ...],
+ *     "rules": [
+ *       { "type": "anti-knight" },
+ *       { "type": "anti-king" },
+ *       { "type": "diagonal", "direction": "main" },
+ *       { "type": "region", "squares": [10, 11, 12, 19, 20, 21, 28, 29, 30] },
+ *       { "type": "cage", "sum": 15, "squares": [0, 1, 9] },
+ *       { "type": "pair", "kind": "white", "squares": [0, 1] },
+ *       { "type": "negative", "kind": "white" },
+ *       { "type": "line", "kind": "thermo", "squares": [9, 10, 11] },
+ *       { "type": "sandwich", "line": 0, "sum": 15 },
+ *       { "type": "sandwich", "column": 4, "sum": 0 },
+ *       { "type": "little-killer", "square": 1, "direction": "down-right", "sum": 40 }
+ *     ]
+ *   }
+ *
+ * A file that is not JSON is a classic sudoku as a line of 81 squares, . or 0
+ * for an empty square.
+ *
+ * Puzzles of several overlapping grids, like Samurai, list each grid with its
+ * offset (line, column) on the canvas. Variant rules can not be combined with
+ * several grids.
+ *
+ *   {
+ *     "grids": [
+ *       { "offset": [0, 0], "layout": [0, 0, 3, ...] },
+ *       { "offset": [6, 6], "layout": [0, 7, 0, ...] }
+ *     ]
+ *   }
+ *
+ */
+pub fn load(puzzle: &Utf8PathBuf, attempts: i32) -> AnyhowResult<Table> {
+    if puzzle.extension() != Some("json") {
+        let layout = line(&std::fs::read_to_string(puzzle)?)?;
+        return Table::new_multi([((0, 0), layout)].to_vec(), attempts);
+    }
+
+    let file = File::open(puzzle)?;
+    let reader = BufReader::new(file);
+    let content: serde_json::Value = serde_json::from_reader(reader)?;
+    parse(puzzle.as_str(), &content, attempts)
+}
+
+/*
+ * Puzzle from its JSON content, see load
+ *
+ */
+pub fn parse(puzzle: &str, content: &serde_json::Value, attempts: i32) -> AnyhowResult<Table> {
+    if content.is_array() {
+        let layout: Vec<usize> = serde_json::from_value(content.clone())?;
+        return Table::new_multi([((0, 0), layout)].to_vec(), attempts);
+    }
+
+    if let Some(entries) = content.get("grids").and_then(|x| x.as_array()) {
+        if content.get("rules").is_some() {
+            return Err(anyhow!(
+                "Puzzle: {puzzle:?} has several grids, variant rules are not supported"
+            ));
+        }
+
+        let mut grids: Vec<((usize, usize), Vec<usize>)> = Vec::new();
+        for entry in entries {
+            grids.push((field(entry, "offset")?, field(entry, "layout")?));
+        }
+        return Table::new_multi(grids, attempts);
+    }
+
+    let layout: Vec<usize> = match content.get("layout") {
+        Some(layout) => serde_json::from_value(layout.clone())?,
+        None => return Err(anyhow!("Puzzle: {puzzle:?} has no layout")),
+    };
+    let mut table = Table::new_multi([((0, 0), layout)].to_vec(), attempts)?;
+
+    if let Some(rules) = content.get("rules") {
+        let Some(entries) = rules.as_array() else {
+            return Err(anyhow!("Puzzle: {puzzle:?} rules must be a list: {rules}"));
+        };
+        for entry in entries {
+            table.add_constraint(constraint(entry)?)?;
+        }
+    }
+
+    Ok(table)
+}
+
+/*
+ * Parse a line of 81 squares, . or 0 for an empty square
+ *
+ */
+pub fn line(content: &str) -> AnyhowResult<Vec<usize>> {
+    let layout = content
+        .chars()
+        .filter(|x| !x.is_whitespace())
+        .map(|x| match x {
+            '.' => Ok(0),
+            x => x
+                .to_digit(10)
+                .map(|x| x as usize)
+                .ok_or_else(|| anyhow!("Invalid square: {x:?}")),
+        })
+        .collect::<AnyhowResult<Vec<usize>>>()?;
+    match layout.len() {
+        81 => Ok(layout),
+        n => Err(anyhow!("A line puzzle has 81 squares, not {n}")),
+    }
+}
+
+/*
+ * Parse one entry of the 'rules' list
+ *
+ */
+fn constraint(entry: &serde_json::Value) -> AnyhowResult<Constraint> {
+    let kind: String = field(entry, "type")?;
+    let constraint = match kind.as_str() {
+        "anti-knight" | "anti-king" => Constraint::Rule(kind.parse()?),
+        "diagonal" => {
+            let direction: String = field(entry, "direction")?;
+            match direction.as_str() {
+                "main" => Constraint::Region(diagonal(false)),
+                "anti" => Constraint::Region(diagonal(true)),
+                _ => return Err(anyhow!("Unknown diagonal direction: {direction:?}")),
+            }
+        }
+        "region" => Constraint::Region(field(entry, "squares")?),
+        "cage" => Constraint::Cage(Cage::new(field(entry, "sum")?, field(entry, "squares")?)?),
+        "pair" => {
+            let kind: String = field(entry, "kind")?;
+            Constraint::Pair(Pair::new(kind.parse()?, field(entry, "squares")?)?)
+        }
+        "negative" => {
+            let kind: String = field(entry, "kind")?;
+            Constraint::Negative(kind.parse()?)
+        }
+        "line" => {
+            let kind: String = field(entry, "kind")?;
+            Constraint::Path(Path::new(kind.parse()?, field(entry, "squares")?)?)
+        }
+        "sandwich" => match (entry.get("line"), entry.get("column")) {
+            (Some(_), None) => Constraint::Clue(Clue::sandwich_line(
+                field(entry, "line")?,
+                field(entry, "sum")?,
+            )?),
+            (None, Some(_)) => Constraint::Clue(Clue::sandwich_column(
+                field(entry, "column")?,
+                field(entry, "sum")?,
+            )?),
+            _ => {
+                return Err(anyhow!(
+                    "Sandwich rule needs either line or column: {entry}"
+                ))
+            }
+        },
+        "little-killer" => {
+            let direction: String = field(entry, "direction")?;
+            Constraint::Clue(Clue::little_killer(
+                field(entry, "square")?,
+                &direction,
+                field(entry, "sum")?,
+            )?)
+        }
+        _ => return Err(anyhow!("Unknown rule type: {kind:?} in {entry}")),
+    };
+    Ok(constraint)
+}
+
+/*
+ * Get a field of a JSON object
+ *
+ */
+fn field<T: DeserializeOwned>(entry: &serde_json::Value, name: &str) -> AnyhowResult<T> {
+    match entry.get(name) {
+        Some(value) => serde_json::from_value(value.clone())
+            .map_err(|e| anyhow!("Invalid field {name:?} in {entry}: {e}")),
+        None => Err(anyhow!("Missing field {name:?} in {entry}")),
+    }
+}
@@ -0,0 +1,77 @@
+use crate::components::table::core::Table;
+use crate::enums::Progress;
+use serde::{Deserialize, Serialize};
+
+/*
+ * Result of solving a puzzle, one line of output
+ *
+ * The grid is the squares as digits, . for an empty square, grid after grid
+ * for several grids.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub puzzle: String,
+    pub outcome: String,
+    pub grid: String,
+    pub iterations: i32,
+    pub snapshots: usize,
+    pub rollbacks: usize,
+}
+
+impl Record {
+    pub fn new(puzzle: &str, table: &Table, progress: &Progress) -> Record {
+        Record {
+            puzzle: String::from(puzzle),
+            outcome: String::from(outcome(progress)),
+            grid: grid(table),
+            iterations: table.get_iterations(),
+            snapshots: table.get_snapshots_taken(),
+            rollbacks: table.get_snapshot_rollbacks(),
+        }
+    }
+
+    /*
+     * The grid followed by the outcome and stats
+     *
+     */
+    pub fn line(&self) -> String {
+        format!(
+            "{} {} [iterations: {}, snapshots: {}, rollbacks: {}]",
+            self.grid, self.outcome, self.iterations, self.snapshots, self.rollbacks
+        )
+    }
+}
+
+/*
+ * Outcome of solving in words
+ *
+ */
+pub fn outcome(progress: &Progress) -> &'static str {
+    match progress {
+        Progress::Solved(_) => "solved",
+        Progress::InProgress(_) => "in-progress",
+        Progress::LimitReached(_) => "limit-reached",
+        Progress::TimedOut(_) => "timed-out",
+        Progress::Cancelled(_) => "cancelled",
+    }
+}
+
+/*
+ * Squares of each grid as a single line of digits, . for an empty square
+ *
+ */
+pub fn grid(table: &Table) -> String {
+    let mut result = String::new();
+    for grid in table.get_grids() {
+        for line in 0..9 {
+            for column in 0..9 {
+                match table.squares[grid.get_square_id(line, column)].value {
+                    0 => result.push('.'),
+                    value => result.push_str(&value.to_string()),
+                }
+            }
+        }
+    }
+    result
+}
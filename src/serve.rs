@@ -0,0 +1,296 @@
+use crate::booklet::difficulty;
+use crate::cancel::CancelToken;
+use crate::components::hint::Hint;
+use crate::components::table::core::Table;
+use crate::enums::{HintLevel, Progress};
+use crate::generate::generate_with;
+use crate::puzzle::{line, parse};
+use crate::record::Record;
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use serde_json::{json, Value};
+use std::fmt;
+use std::io::Read;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tiny_http::{Header, Request, Response, Server};
+
+/*
+ * Limits on each request
+ *
+ * A body larger than max_body is refused, solving stops after timeout and
+ * attempts is the limit of guesses as for the command line.
+ *
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Limits {
+    pub max_body: usize,
+    pub timeout: Duration,
+    pub attempts: i32,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            max_body: 65536,
+            timeout: Duration::from_secs(10),
+            attempts: 500,
+        }
+    }
+}
+
+/*
+ * Solver over HTTP, JSON in and out
+ *
+ * Requests are handled by a pool of threads sharing the listening socket,
+ * each taking the next request as it is done with the last one.
+ *
+ *   POST /solve     { "puzzle": ... }                -> record of solving
+ *   POST /validate  { "puzzle": ... }                -> { "valid", "error" }
+ *   POST /grade     { "puzzle": ... }                -> { "difficulty", "record" }
+ *   POST /hint      { "puzzle": ... }                -> { "hint" }, null when none
+ *   POST /generate  { "givens": 30 }                 -> { "puzzle" }
+ *
+ * A puzzle is anything a puzzle file holds, see puzzle::load, or a string of
+ * 81 squares. Errors are { "error" }, with a 4xx status for a bad request or
+ * 500 when the solver fails on a puzzle it accepted. Generating stops at the
+ * timeout as solving does.
+ *
+ */
+pub struct Service {
+    server: Arc<Server>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl Service {
+    pub fn bind(address: &str, threads: usize, limits: Limits) -> AnyhowResult<Service> {
+        let server = Arc::new(
+            Server::http(address).map_err(|e| anyhow!("Unable to bind {address:?}: {e}"))?,
+        );
+        let workers = (0..threads.max(1))
+            .map(|_| {
+                let server = Arc::clone(&server);
+                let limits = limits.clone();
+                std::thread::spawn(move || {
+                    while let Ok(request) = server.recv() {
+                        respond(request, &limits);
+                    }
+                })
+            })
+            .collect();
+        Ok(Service { server, workers })
+    }
+
+    pub fn address(&self) -> AnyhowResult<SocketAddr> {
+        self.server
+            .server_addr()
+            .to_ip()
+            .ok_or_else(|| anyhow!("Not listening on an IP address"))
+    }
+
+    /*
+     * Serve until stopped
+     *
+     */
+    pub fn join(self) {
+        for worker in self.workers {
+            let _ = worker.join();
+        }
+    }
+
+    /*
+     * Stop taking requests, those being handled are finished first
+     *
+     */
+    pub fn stop(self) {
+        for _ in 0..self.workers.len() {
+            self.server.unblock();
+        }
+        self.join();
+    }
+}
+
+fn respond(mut request: Request, limits: &Limits) {
+    let method = request.method().to_string();
+    let url = String::from(request.url());
+
+    let (status, body) = match request.body_length() {
+        Some(length) if length > limits.max_body => too_large(limits),
+        _ => {
+            let mut body = String::new();
+            match request
+                .as_reader()
+                .take(limits.max_body as u64 + 1)
+                .read_to_string(&mut body)
+            {
+                Ok(n) if n > limits.max_body => too_large(limits),
+                Ok(_) => handle(&method, &url, &body, limits),
+                Err(e) => (400, error(format!("Unable to read body: {e}"))),
+            }
+        }
+    };
+
+    let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(header);
+    if let Err(e) = request.respond(response) {
+        log::warn!("Unable to respond to {url}: {e}");
+    }
+}
+
+/*
+ * Handle a request, the status and JSON body to respond with
+ *
+ */
+pub fn handle(method: &str, url: &str, body: &str, limits: &Limits) -> (u16, Value) {
+    let endpoint = url.split('?').next().unwrap_or_default();
+    let result = match endpoint {
+        "/solve" | "/validate" | "/grade" | "/hint" | "/generate" if method != "POST" => {
+            return (405, error(format!("Use POST for {endpoint}")))
+        }
+        "/solve" => request(body).and_then(|x| solve(&x, limits)),
+        "/validate" => request(body).and_then(|x| validate(&x, limits)),
+        "/grade" => request(body).and_then(|x| grade(&x, limits)),
+        "/hint" => request(body).and_then(|x| hint(&x, limits)),
+        "/generate" => request(body).and_then(|x| generate_puzzle(&x, limits)),
+        _ => return (404, error(format!("No such endpoint: {endpoint}"))),
+    };
+    match result {
+        Ok(value) => (200, value),
+        Err(e) if e.is::<SolverError>() => (500, error(e.to_string())),
+        Err(e) => (400, error(e.to_string())),
+    }
+}
+
+/*
+ * The solver failing on a puzzle it accepted, not a bad request
+ *
+ */
+#[derive(Debug)]
+pub(crate) struct SolverError(String);
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for SolverError {}
+
+pub(crate) fn solver<T>(result: AnyhowResult<T>) -> AnyhowResult<T> {
+    result.map_err(|e| anyhow::Error::new(SolverError(e.to_string())))
+}
+
+fn error(message: String) -> Value {
+    json!({ "error": message })
+}
+
+fn too_large(limits: &Limits) -> (u16, Value) {
+    (
+        413,
+        error(format!("Body is larger than {} bytes", limits.max_body)),
+    )
+}
+
+fn request(body: &str) -> AnyhowResult<Value> {
+    match body.trim() {
+        "" => Ok(json!({})),
+        body => serde_json::from_str(body).map_err(|e| anyhow!("Invalid JSON: {e}")),
+    }
+}
+
+pub(crate) fn puzzle(request: &Value, limits: &Limits) -> AnyhowResult<Table> {
+    match request.get("puzzle") {
+        Some(Value::String(content)) => {
+            Table::new_multi([((0, 0), line(content)?)].to_vec(), limits.attempts)
+        }
+        Some(content) => parse("puzzle", content, limits.attempts),
+        None => Err(anyhow!("Missing field: \"puzzle\"")),
+    }
+}
+
+/*
+ * The puzzle of a request, refused when its givens conflict
+ *
+ * The solver assumes the givens hold, it is not to be started otherwise.
+ *
+ */
+pub(crate) fn valid_puzzle(request: &Value, limits: &Limits) -> AnyhowResult<Table> {
+    let mut table = puzzle(request, limits)?;
+    match table.validate()? {
+        true => Ok(table),
+        false => Err(anyhow!("Givens conflict")),
+    }
+}
+
+fn run(request: &Value, limits: &Limits) -> AnyhowResult<(Table, Progress)> {
+    let mut table = valid_puzzle(request, limits)?;
+    let token = CancelToken::new().with_timeout(limits.timeout);
+    let progress = solver(table.solve_with(&token))?;
+    Ok((table, progress))
+}
+
+fn solve(request: &Value, limits: &Limits) -> AnyhowResult<Value> {
+    let (table, progress) = run(request, limits)?;
+    Ok(serde_json::to_value(Record::new(
+        "puzzle", &table, &progress,
+    ))?)
+}
+
+fn validate(request: &Value, limits: &Limits) -> AnyhowResult<Value> {
+    let result = puzzle(request, limits).and_then(|mut table| match table.validate()? {
+        true => Ok(()),
+        false => Err(anyhow!("givens conflict")),
+    });
+    Ok(match result {
+        Ok(()) => json!({ "valid": true, "error": null }),
+        Err(e) => json!({ "valid": false, "error": e.to_string() }),
+    })
+}
+
+fn grade(request: &Value, limits: &Limits) -> AnyhowResult<Value> {
+    let (table, progress) = run(request, limits)?;
+    let solved = matches!(progress, Progress::Solved(_));
+    Ok(json!({
+        "difficulty": difficulty(&table, solved),
+        "record": Record::new("puzzle", &table, &progress),
+    }))
+}
+
+fn hint(request: &Value, limits: &Limits) -> AnyhowResult<Value> {
+    let table = valid_puzzle(request, limits)?;
+    Ok(json!({ "hint": solver(table.hint())?.map(|x| hint_value(&x)) }))
+}
+
+/*
+ * A hint as JSON, with the containers and technique in words
+ *
+ */
+pub(crate) fn hint_value(x: &Hint) -> Value {
+    json!({
+        "square": x.square_id,
+        "line": x.line,
+        "column": x.column,
+        "value": x.value,
+        "container": x.container.to_string(),
+        "container_id": x.container_id,
+        "technique": x.technique.to_string(),
+        "text": x.describe(&HintLevel::ANSWER),
+    })
+}
+
+fn generate_puzzle(request: &Value, limits: &Limits) -> AnyhowResult<Value> {
+    let givens = match request.get("givens") {
+        Some(givens) => givens
+            .as_u64()
+            .filter(|x| *x <= 81)
+            .ok_or_else(|| anyhow!("Givens must be a number up to 81: {givens}"))?,
+        None => 30,
+    };
+    let token = CancelToken::new().with_timeout(limits.timeout);
+    let puzzle = solver(generate_with(givens as usize, limits.attempts, &token))?;
+    Ok(json!({ "puzzle": puzzle }))
+}
@@ -66,3 +66,39 @@ pub fn compare(a: &(&usize, &Vec<usize>), b: &(&usize, &Vec<usize>)) -> std::cmp
     }
     std::cmp::Ordering::Less
 }
+
+/*
+ * Smallest candidate, or 1 if there are none
+ */
+pub fn min_candidate(candidates: &[usize]) -> usize {
+    candidates.iter().copied().min().unwrap_or(1)
+}
+
+/*
+ * Largest candidate, or 9 if there are none
+ */
+pub fn max_candidate(candidates: &[usize]) -> usize {
+    candidates.iter().copied().max().unwrap_or(9)
+}
+
+/*
+ * Prune candidates of squares that must reach a sum
+ *
+ * Each square must leave room for the other squares to reach the sum.
+ */
+pub fn prune_sum(sum: usize, candidates: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let low: usize = candidates.iter().map(|x| min_candidate(x)).sum();
+    let high: usize = candidates.iter().map(|x| max_candidate(x)).sum();
+
+    candidates
+        .iter()
+        .map(|c| {
+            let others_low = low - min_candidate(c);
+            let others_high = high - max_candidate(c);
+            c.iter()
+                .copied()
+                .filter(|x| sum >= x + others_low && sum <= x + others_high)
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,187 @@
+use crate::components::square::Square;
+use crate::components::table::core::Table;
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use std::fmt;
+
+/*
+ * A unit of squares where a value may not repeat
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unit {
+    LINE,
+    COLUMN,
+    ABOX,
+    REGION,
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Unit::LINE => write!(f, "line"),
+            Unit::COLUMN => write!(f, "column"),
+            Unit::ABOX => write!(f, "box"),
+            Unit::REGION => write!(f, "region"),
+        }
+    }
+}
+
+/*
+ * A square with its line and column
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Place {
+    pub square_id: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Place {
+    pub fn of(square: &Square) -> Place {
+        Place {
+            square_id: square.id,
+            line: square.line_ids[0] % 9,
+            column: square.column_ids[0] % 9,
+        }
+    }
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line + 1, self.column + 1)
+    }
+}
+
+/*
+ * Something wrong with a solution
+ *
+ * Squares are given by line, column and box, numbered from 0 as for Hint and
+ * from 1 when described. A repeated value lists every square of the unit
+ * holding it.
+ *
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    EMPTY {
+        square_id: usize,
+        line: usize,
+        column: usize,
+        abox: usize,
+    },
+    GIVEN {
+        square_id: usize,
+        line: usize,
+        column: usize,
+        abox: usize,
+        given: usize,
+        value: usize,
+    },
+    DUPLICATE {
+        unit: Unit,
+        id: usize,
+        value: usize,
+        squares: Vec<Place>,
+    },
+    CONSTRAINT {
+        kind: String,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Violation::EMPTY {
+                line, column, abox, ..
+            } => write!(
+                f,
+                "Line {}, column {} in box {} is empty",
+                line + 1,
+                column + 1,
+                abox + 1
+            ),
+            Violation::GIVEN {
+                line,
+                column,
+                abox,
+                given,
+                value,
+                ..
+            } => write!(
+                f,
+                "Line {}, column {} in box {} is {value}, not the given {given}",
+                line + 1,
+                column + 1,
+                abox + 1
+            ),
+            Violation::DUPLICATE {
+                unit,
+                id,
+                value,
+                squares,
+            } => {
+                let places: Vec<String> = squares.iter().map(|x| x.to_string()).collect();
+                write!(
+                    f,
+                    "{value} is repeated in {unit} {}: {}",
+                    id + 1,
+                    places.join(" and ")
+                )
+            }
+            Violation::CONSTRAINT { kind } => write!(f, "The {kind} of the puzzle do not hold"),
+        }
+    }
+}
+
+/*
+ * Verify a solution to a puzzle
+ *
+ * The solution is the squares of the puzzle in the same order, 0 for a square
+ * left empty. It is a solution when there are no violations: every square is
+ * set, the givens are kept and the table validates with the values.
+ *
+ */
+pub fn verify(puzzle: &Table, solution: &[usize]) -> AnyhowResult<Vec<Violation>> {
+    if solution.len() != puzzle.squares.len() {
+        return Err(anyhow!(
+            "A solution has {} squares, not {}",
+            puzzle.squares.len(),
+            solution.len()
+        ));
+    }
+    if let Some(value) = solution.iter().find(|x| **x > 9) {
+        return Err(anyhow!("Invalid square: {value}"));
+    }
+
+    // Violations are found from the values alone, they are set as they are
+    let mut table = puzzle.clone();
+    let mut violations: Vec<Violation> = Vec::new();
+    for square in &puzzle.squares {
+        let Place { line, column, .. } = Place::of(square);
+        match (square.value, solution[square.id]) {
+            (_, 0) => violations.push(Violation::EMPTY {
+                square_id: square.id,
+                line,
+                column,
+                abox: square.abox_id,
+            }),
+            (given, value) if given == value => continue,
+            (0, value) => table.squares[square.id].value = value,
+            (given, value) => {
+                violations.push(Violation::GIVEN {
+                    square_id: square.id,
+                    line,
+                    column,
+                    abox: square.abox_id,
+                    given,
+                    value,
+                });
+                table.squares[square.id].value = value;
+            }
+        }
+    }
+
+    violations.extend(table.violations()?);
+    Ok(violations)
+}
@@ -0,0 +1,231 @@
+use crate::cancel::CancelToken;
+use crate::components::table::core::Table;
+use crate::enums::Step;
+use crate::record::{grid, outcome, Record};
+use crate::serve::{hint_value, puzzle, solver, valid_puzzle, Limits, SolverError};
+use anyhow::anyhow;
+use anyhow::Result as AnyhowResult;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+/*
+ * Solver sessions driven by JSON-RPC 2.0, a request per line
+ *
+ * A puzzle loaded with load is kept as a session, later calls give its id and
+ * work on the table as it was left. Calls other than load and close take a
+ * puzzle instead of a session as well, which is not kept.
+ *
+ *   load        { "puzzle" }               -> { "session", "grid" }
+ *   step        { "session" }              -> { "step", "technique", "eliminations",
+ *                                               "grid", "record" }
+ *   solve       { "session" or "puzzle" }  -> record of solving
+ *   hint        { "session" or "puzzle" }  -> { "hint" }, null when none
+ *   candidates  { "session" or "puzzle" }  -> { "candidates" }, per square
+ *   validate    { "session" or "puzzle" }  -> { "valid", "error" }
+ *   close       { "session" }              -> { "closed" }
+ *
+ * A puzzle whose givens conflict is refused, other than by validate, with
+ * invalid params. The solver failing is an internal error and closes the
+ * session it failed on. Each response is a single line, in the order of the
+ * requests. A notification, a request without id, gets no response.
+ *
+ */
+pub struct Worker {
+    sessions: HashMap<u64, Table>,
+    next: u64,
+    limits: Limits,
+}
+
+impl Worker {
+    pub fn new(limits: Limits) -> Worker {
+        Worker {
+            sessions: HashMap::new(),
+            next: 1,
+            limits,
+        }
+    }
+
+    /*
+     * Handle a request line, the response unless it was a notification
+     *
+     */
+    pub fn handle(&mut self, line: &str) -> Option<Value> {
+        if line.len() > self.limits.max_body {
+            let message = format!("Request is larger than {} bytes", self.limits.max_body);
+            return Some(error(Value::Null, -32600, message));
+        }
+        let request: Value = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(e) => return Some(error(Value::Null, -32700, format!("Parse error: {e}"))),
+        };
+
+        let id = request.get("id").cloned();
+        let response_id = id.clone().unwrap_or(Value::Null);
+        let Some(method) = request.get("method").and_then(|x| x.as_str()) else {
+            return Some(error(response_id, -32600, String::from("Missing method")));
+        };
+        let params = request.get("params").cloned().unwrap_or(json!({}));
+
+        let response = match self.call(method, &params) {
+            Ok(Some(result)) => json!({ "jsonrpc": "2.0", "id": response_id, "result": result }),
+            Ok(None) => error(response_id, -32601, format!("No such method: {method}")),
+            Err(e) if e.is::<SolverError>() => {
+                self.close_failed(&params);
+                error(response_id, -32603, e.to_string())
+            }
+            Err(e) => error(response_id, -32602, e.to_string()),
+        };
+        id.map(|_| response)
+    }
+
+    fn call(&mut self, method: &str, params: &Value) -> AnyhowResult<Option<Value>> {
+        let result = match method {
+            "load" => {
+                let table = valid_puzzle(params, &self.limits)?;
+                let session = self.next;
+                self.next += 1;
+                let result = json!({ "session": session, "grid": grid(&table) });
+                self.sessions.insert(session, table);
+                result
+            }
+            "step" => {
+                let table = self.session(params)?;
+                let step = solver(table.step())?;
+                let (name, technique, record) = match &step {
+                    Step::Deduced(technique) => ("deduced", Some(technique.to_string()), None),
+                    Step::Guessed => ("guessed", None, None),
+                    Step::RolledBack => ("rolled-back", None, None),
+                    Step::Done(progress) => {
+                        let record = Record::new("session", table, progress);
+                        (outcome(progress), None, Some(record))
+                    }
+                };
+                let eliminations: Vec<String> = table
+                    .get_eliminations()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect();
+                json!({
+                    "step": name,
+                    "technique": technique,
+                    "eliminations": eliminations,
+                    "grid": grid(table),
+                    "record": record,
+                })
+            }
+            "solve" => {
+                let token = CancelToken::new().with_timeout(self.limits.timeout);
+                match params.get("session") {
+                    Some(_) => {
+                        let table = self.session(params)?;
+                        let progress = solver(table.solve_with(&token))?;
+                        serde_json::to_value(Record::new("session", table, &progress))?
+                    }
+                    None => {
+                        let mut table = valid_puzzle(params, &self.limits)?;
+                        let progress = solver(table.solve_with(&token))?;
+                        serde_json::to_value(Record::new("puzzle", &table, &progress))?
+                    }
+                }
+            }
+            "hint" => {
+                let hint = solver(self.table(params)?.hint())?;
+                json!({ "hint": hint.map(|x| hint_value(&x)) })
+            }
+            "candidates" => {
+                let mut table = self.table(params)?;
+                solver(table.update().map(|_| ()))?;
+                let candidates: Vec<Vec<usize>> = table
+                    .squares
+                    .iter()
+                    .map(|x| {
+                        let mut potentials = x.potentials.clone();
+                        potentials.sort();
+                        potentials
+                    })
+                    .collect();
+                json!({ "candidates": candidates })
+            }
+            "validate" => {
+                let mut table = match params.get("session") {
+                    Some(_) => self.session(params)?.clone(),
+                    None => puzzle(params, &self.limits)?,
+                };
+                match table.validate()? {
+                    true => json!({ "valid": true, "error": null }),
+                    false => json!({ "valid": false, "error": "givens conflict" }),
+                }
+            }
+            "close" => {
+                let session = session_id(params)?;
+                match self.sessions.remove(&session) {
+                    Some(_) => json!({ "closed": session }),
+                    None => return Err(anyhow!("No such session: {session}")),
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(result))
+    }
+
+    /*
+     * The session the solver failed on may be left half changed, it is not kept
+     *
+     */
+    fn close_failed(&mut self, params: &Value) {
+        if let Ok(session) = session_id(params) {
+            self.sessions.remove(&session);
+        }
+    }
+
+    fn session(&mut self, params: &Value) -> AnyhowResult<&mut Table> {
+        let session = session_id(params)?;
+        self.sessions
+            .get_mut(&session)
+            .ok_or_else(|| anyhow!("No such session: {session}"))
+    }
+
+    /*
+     * Copy of the session's table, or the puzzle given when its givens hold
+     *
+     */
+    fn table(&mut self, params: &Value) -> AnyhowResult<Table> {
+        match params.get("session") {
+            Some(_) => Ok(self.session(params)?.clone()),
+            None => valid_puzzle(params, &self.limits),
+        }
+    }
+}
+
+fn session_id(params: &Value) -> AnyhowResult<u64> {
+    match params.get("session") {
+        Some(session) => session
+            .as_u64()
+            .ok_or_else(|| anyhow!("Session must be a number: {session}")),
+        None => Err(anyhow!("Missing field: \"session\"")),
+    }
+}
+
+fn error(id: Value, code: i32, message: String) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/*
+ * Answer requests from input, one per line, until it ends
+ *
+ */
+pub fn worker(input: impl BufRead, mut output: impl Write, limits: Limits) -> AnyhowResult<()> {
+    let mut worker = Worker::new(limits);
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = worker.handle(&line) {
+            writeln!(output, "{response}")?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
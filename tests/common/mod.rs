@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+/*
+ * A grid one square short of solved, square 18 should be 1
+ *
+ * ╔═══╤═══╤═══╦═══╤═══╤═══╦═══╤═══╤═══╗
+ * ║ 8 │ 5 │ 9 ║ 6 │ 1 │ 2 ║ 4 │ 3 │ 7 ║
+ * ╟───┼───┼───╫───┼───┼───╫───┼───┼───╢
+ * ║ 7 │ 2 │ 3 ║ 8 │ 5 │ 4 ║ 1 │ 6 │ 9 ║
+ * ╟───┼───┼───╫───┼───┼───╫───┼───┼───╢
+ * ║   │ 6 │ 4 ║ 3 │ 7 │ 9 ║ 5 │ 2 │ 8 ║
+ * ╠═══╪═══╪═══╬═══╪═══╪═══╬═══╪═══╪═══╣
+ * ...
+ */
+pub fn near_solved() -> Vec<usize> {
+    [
+        8, 5, 9, 6, 1, 2, 4, 3, 7, 7, 2, 3, 8, 5, 4, 1, 6, 9, 0, 6, 4, 3, 7, 9, 5, 2, 8, 9, 8, 6,
+        1, 4, 7, 3, 5, 2, 3, 7, 5, 2, 6, 8, 9, 1, 4, 2, 4, 1, 5, 9, 3, 7, 8, 6, 4, 3, 2, 9, 8, 1,
+        6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
+    ]
+    .to_vec()
+}
+
+/*
+ * A puzzle solved by the engine alone, as a line of 81 squares
+ */
+pub const PUZZLE: &str =
+    "..2753...5..89.4...6.1...58.49.123.562.9...4..3147596.......57.3.....8.9178560.3.";
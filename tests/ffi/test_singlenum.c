@@ -0,0 +1,60 @@
+/*
+ * Exercise the C interface: create, hint, solve, read back and free
+ *
+ */
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "singlenum.h"
+
+static const char *PUZZLE =
+    "..2753...5..89.4...6.1...58.49.123.562.9...4..3147596.......57.3.....8.9178560.3.";
+
+int main(void) {
+    uint8_t squares[81];
+    for (int i = 0; i < 81; i++) {
+        squares[i] = PUZZLE[i] == '.' ? 0 : (uint8_t)(PUZZLE[i] - '0');
+    }
+
+    /* Wrong length, a square above 9 or conflicting givens */
+    assert(singlenum_table_new(squares, 80, 500) == NULL);
+    uint8_t invalid[81];
+    memcpy(invalid, squares, sizeof(invalid));
+    invalid[0] = 10;
+    assert(singlenum_table_new(invalid, 81, 500) == NULL);
+    uint8_t conflict[81];
+    memcpy(conflict, squares, sizeof(conflict));
+    conflict[0] = 2;
+    assert(singlenum_table_new(conflict, 81, 500) == NULL);
+
+    SinglenumTable *table = singlenum_table_new(squares, 81, 500);
+    assert(table != NULL);
+
+    SinglenumHint hint;
+    assert(singlenum_hint(table, &hint) == 1);
+    assert(squares[hint.square] == 0);
+    assert(hint.value >= 1 && hint.value <= 9);
+    assert(hint.square == hint.line * 9 + hint.column);
+
+    assert(singlenum_solve(table, 1000) == SinglenumOutcome_SOLVED);
+
+    uint8_t grid[81];
+    assert(singlenum_grid(table, grid, 10) == -1);
+    assert(singlenum_grid(table, grid, sizeof(grid)) == 81);
+    for (int i = 0; i < 81; i++) {
+        assert(grid[i] >= 1 && grid[i] <= 9);
+        assert(squares[i] == 0 || squares[i] == grid[i]);
+    }
+    assert(grid[hint.square] == hint.value);
+
+    SinglenumStats stats = singlenum_stats(table);
+    assert(stats.iterations > 0);
+    assert(singlenum_hint(table, &hint) == 0);
+
+    singlenum_table_free(table);
+    singlenum_table_free(NULL);
+
+    printf("ok\n");
+    return 0;
+}
@@ -0,0 +1,41 @@
+use std::process::{Command, Stdio};
+
+/*
+ * Run the binary with stdout piped, the error printed when it fails
+ *
+ */
+fn run(args: &[&str]) -> (String, bool) {
+    let result = Command::new(env!("CARGO_BIN_EXE_singlenum"))
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    (
+        String::from_utf8(result.stderr).unwrap(),
+        result.status.success(),
+    )
+}
+
+/*
+ * Animating redraws a board in place, it is refused for one line per puzzle
+ * and when stdout is not a terminal, as here.
+ */
+#[test]
+fn test_01_animate_refused() {
+    let puzzle = "puzzles/cat/medium/puzzle_aa.json";
+
+    let (error, success) = run(&["solve", puzzle, "--animate", "--output", "line"]);
+    assert!(!success);
+    assert!(error.contains("Animating draws boards, not one line per puzzle"));
+
+    let (error, success) = run(&["solve", "-", "--animate"]);
+    assert!(!success);
+    assert!(error.contains("Animating draws boards, not one line per puzzle"));
+
+    let (error, success) = run(&["--color", "never", "solve", puzzle, "--animate"]);
+    assert!(!success);
+    assert!(error.contains("stdout must be a terminal"));
+
+    let (_, success) = run(&["solve", puzzle]);
+    assert!(success);
+}
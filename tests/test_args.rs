@@ -0,0 +1,63 @@
+use clap::Parser;
+use singlenum::args::{Arguments, Command, Format, Style};
+use singlenum::cancel::CancelToken;
+use std::time::Duration;
+
+#[test]
+fn test_01_args_solve() {
+    let args = Arguments::try_parse_from([
+        "singlenum",
+        "solve",
+        "puzzles",
+        "--novisual",
+        "--candidates",
+        "--style",
+        "ascii",
+        "--timeout",
+        "2s",
+    ])
+    .unwrap();
+    assert_eq!(args.style, Style::ASCII);
+    assert_eq!(args.timeout, Some(Duration::from_secs(2)));
+    let Command::Solve(solve) = args.command else {
+        panic!("Expected solve");
+    };
+    assert_eq!(solve.path, "puzzles");
+    assert!(!solve.novisual);
+    assert!(solve.board.candidates);
+}
+
+#[test]
+fn test_01_args_subcommands() {
+    let args = Arguments::try_parse_from(["singlenum", "convert", "01.json", "--to", "line"]);
+    assert!(matches!(
+        args.unwrap().command,
+        Command::Convert {
+            to: Format::LINE,
+            ..
+        }
+    ));
+
+    // Options belong to their subcommand, global ones go anywhere
+    assert!(Arguments::try_parse_from(["singlenum", "draw", "01.json", "--animate"]).is_err());
+    assert!(
+        Arguments::try_parse_from(["singlenum", "--attempts", "9", "grade", "01.json"]).is_ok()
+    );
+    assert!(Arguments::try_parse_from(["singlenum", "solve"]).is_err());
+}
+
+#[test]
+fn test_01_args_timeout_range() {
+    let parse = |timeout: &str| {
+        Arguments::try_parse_from(["singlenum", "solve", "puzzles", "--timeout", timeout])
+    };
+
+    // Longer than a Duration holds
+    assert!(parse("1e30").is_err());
+    assert!(parse("-1").is_err());
+
+    // A Duration, but past any point in time, never times out
+    let args = parse("1e19").unwrap();
+    let token = CancelToken::new().with_timeout(args.timeout.unwrap());
+    assert!(!token.timed_out());
+}
@@ -0,0 +1,87 @@
+use camino::Utf8PathBuf;
+use singlenum::bench::{compare, measure, Baseline, Measure};
+use singlenum::components::table::core::Table;
+
+mod common;
+
+fn sample(puzzle: &str, solved: usize, median_ms: f64, iterations: i32) -> Measure {
+    Measure {
+        puzzle: String::from(puzzle),
+        runs: 5,
+        solved,
+        median_ms,
+        iterations,
+        snapshots: 0,
+        rollbacks: 0,
+    }
+}
+
+#[test]
+fn test_01_bench_measure() {
+    let configuration = common::near_solved();
+    let table = Table::new(configuration, 500).unwrap();
+
+    let measure = measure("01.json", &table, 3, None).unwrap();
+    assert_eq!(measure.puzzle, "01.json");
+    assert_eq!((measure.runs, measure.solved), (3, 3));
+    assert_eq!(measure.snapshots, 0);
+    assert!(measure.median_ms >= 0.0);
+
+    // The table given is not changed
+    assert_eq!(table.squares[18].value, 0);
+}
+
+#[test]
+fn test_01_bench_compare() {
+    let baseline = Baseline {
+        measures: vec![
+            sample("a.json", 5, 10.0, 100),
+            sample("b.json", 5, 10.0, 100),
+        ],
+    };
+    let within = Baseline {
+        measures: vec![
+            sample("a.json", 5, 11.9, 119),
+            sample("b.json", 5, 0.5, 50),
+            sample("new.json", 0, 100.0, 500),
+        ],
+    };
+    assert_eq!(compare(&baseline, &within, 20.0), []);
+
+    let worse = Baseline {
+        measures: vec![
+            sample("a.json", 5, 12.5, 100),
+            sample("b.json", 3, 10.0, 130),
+        ],
+    };
+    let regressions = compare(&baseline, &worse, 20.0);
+    let found: Vec<(&str, &str)> = regressions
+        .iter()
+        .map(|x| (x.puzzle.as_str(), x.metric.as_str()))
+        .collect();
+    assert_eq!(found, [("a.json", "median_ms"), ("b.json", "solved")]);
+    assert_eq!(
+        regressions[0].to_string(),
+        "a.json: median_ms 10.00 -> 12.50 (+25%)"
+    );
+
+    // Small differences in time are noise
+    let fast = Baseline {
+        measures: vec![sample("a.json", 5, 0.1, 100)],
+    };
+    let slower = Baseline {
+        measures: vec![sample("a.json", 5, 0.5, 100)],
+    };
+    assert_eq!(compare(&fast, &slower, 20.0), []);
+}
+
+#[test]
+fn test_01_bench_baseline_file() {
+    let file = Utf8PathBuf::from(format!("{}/bench.json", env!("CARGO_TARGET_TMPDIR")));
+    let baseline = Baseline {
+        measures: vec![sample("a.json", 4, 1.5, 52)],
+    };
+    baseline.save(&file).unwrap();
+    assert_eq!(Baseline::load(&file).unwrap(), baseline);
+    assert!(baseline.get_measure("b.json").is_none());
+}
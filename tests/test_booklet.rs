@@ -0,0 +1,79 @@
+use singlenum::booklet::{booklet, difficulty, TIMEOUT};
+use singlenum::components::table::core::Table;
+use std::time::Duration;
+
+mod common;
+
+fn table() -> Table {
+    Table::new(common::near_solved(), 10).unwrap()
+}
+
+#[test]
+fn test_01_difficulty() {
+    let mut table = table();
+    table.solve().unwrap();
+    assert_eq!(difficulty(&table, true), "Easy");
+    assert_eq!(difficulty(&table, false), "Unsolved");
+}
+
+#[test]
+fn test_01_booklet() {
+    let puzzles = [
+        (String::from("first"), table()),
+        (String::from("<second>"), table()),
+        (String::from("third"), table()),
+    ]
+    .to_vec();
+    let html = booklet(puzzles, 2, TIMEOUT).unwrap();
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<h2>1. first</h2>"));
+    assert!(html.contains("<h2>2. &lt;second&gt;</h2>"));
+    assert!(html.contains("<h1 style=\"grid-column: 1 / -1;\">Solutions</h1>"));
+
+    // Two pages of puzzles, one of solutions, each puzzle twice
+    assert_eq!(html.matches("<section").count(), 3);
+    assert_eq!(html.matches("<svg").count(), 6);
+}
+
+#[test]
+fn test_01_booklet_unsolved() {
+    // An empty grid is not solved in a single attempt
+    let puzzles = [
+        (String::from("solved"), table()),
+        (String::from("empty"), Table::new(vec![0; 81], 1).unwrap()),
+    ]
+    .to_vec();
+    let html = booklet(puzzles, 2, TIMEOUT).unwrap();
+
+    assert!(html.contains("<div class=\"difficulty\">Unsolved</div>"));
+    assert!(html.contains("<h2>2. empty</h2>\n<div class=\"unsolved\">No solution found</div>"));
+    assert_eq!(html.matches("<svg").count(), 3);
+}
+
+#[test]
+fn test_01_booklet_stopped() {
+    // Givens that hold, but square 0 has no value left
+    let unsolvable: Vec<usize> = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+        .into_iter()
+        .chain([0; 71])
+        .collect();
+    let puzzles = [
+        (String::from("solved"), table()),
+        (String::from("none"), Table::new(unsolvable, 10).unwrap()),
+    ]
+    .to_vec();
+    let html = booklet(puzzles.clone(), 2, TIMEOUT).unwrap();
+    assert!(html.contains("<div class=\"difficulty\">Easy</div>"));
+    assert!(html.contains("<div class=\"difficulty\">No solution</div>"));
+    assert_eq!(html.matches("<svg").count(), 3);
+
+    // Out of time before the first step
+    let html = booklet(puzzles, 2, Duration::ZERO).unwrap();
+    assert_eq!(
+        html.matches("<div class=\"difficulty\">Timed out</div>")
+            .count(),
+        2
+    );
+    assert_eq!(html.matches("No solution found").count(), 2);
+}
@@ -0,0 +1,60 @@
+use singlenum::args::duration;
+use singlenum::cancel::CancelToken;
+use singlenum::components::table::core::Table;
+use singlenum::enums::Progress;
+use std::time::Duration;
+
+mod common;
+
+#[test]
+fn test_01_cancel_token() {
+    let token = CancelToken::new();
+    let timed = token.with_timeout(Duration::ZERO);
+    assert!(!token.is_cancelled() && !token.timed_out());
+    assert!(timed.timed_out());
+
+    // Clones share the flag, the deadline is per token
+    timed.cancel();
+    assert!(token.is_cancelled());
+    assert!(!token.timed_out());
+}
+
+#[test]
+fn test_01_solve_with() {
+    let mut table = Table::new([0; 81].to_vec(), 500).unwrap();
+    let before = table.clone();
+
+    let token = CancelToken::new();
+    token.cancel();
+    assert!(matches!(
+        table.solve_with(&token).unwrap(),
+        Progress::Cancelled(_)
+    ));
+    assert_eq!(table, before);
+
+    let token = CancelToken::new().with_timeout(Duration::ZERO);
+    match table.solve_with(&token).unwrap() {
+        Progress::TimedOut(msg) => {
+            assert_eq!(msg, "[iterations: 0, snapshots: 0, rollbacks: 0]")
+        }
+        _ => panic!("Expected timed out"),
+    }
+
+    let configuration = common::near_solved();
+    let mut table = Table::new(configuration, 500).unwrap();
+    let token = CancelToken::new().with_timeout(Duration::from_secs(60));
+    assert!(matches!(
+        table.solve_with(&token).unwrap(),
+        Progress::Solved(_)
+    ));
+}
+
+#[test]
+fn test_01_duration() {
+    assert_eq!(duration("2s"), Ok(Duration::from_secs(2)));
+    assert_eq!(duration("500ms"), Ok(Duration::from_millis(500)));
+    assert_eq!(duration("1m"), Ok(Duration::from_secs(60)));
+    assert_eq!(duration("1.5"), Ok(Duration::from_millis(1500)));
+    assert!(duration("abc").is_err());
+    assert!(duration("-1s").is_err());
+}
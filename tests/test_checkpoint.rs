@@ -0,0 +1,38 @@
+use camino::Utf8PathBuf;
+use singlenum::checkpoint::{Checkpoint, Current, Done};
+use singlenum::components::table::core::Table;
+
+#[test]
+fn test_01_checkpoint_save_load() {
+    let file = Utf8PathBuf::from(format!(
+        "{}/singlenum-checkpoint-{}.json",
+        std::env::temp_dir().display(),
+        std::process::id()
+    ));
+    assert_eq!(Checkpoint::load(&file).unwrap(), Checkpoint::default());
+
+    let mut checkpoint = Checkpoint::default();
+    checkpoint.finish("01.json", true, Some(false), "[iterations: 1]");
+    checkpoint.current = Some(Current {
+        puzzle: String::from("02.json"),
+        table: Table::new([0; 81].to_vec(), 1).unwrap(),
+    });
+    checkpoint.save(&file).unwrap();
+
+    let mut loaded = Checkpoint::load(&file).unwrap();
+    std::fs::remove_file(&file).unwrap();
+    assert_eq!(loaded, checkpoint);
+    assert!(loaded.get_done("01.json").unwrap().solved);
+    assert_eq!(loaded.get_done("01.json").unwrap().matches, Some(false));
+    assert!(loaded.get_done("02.json").is_none());
+
+    // Only the current puzzle resumes, and only once
+    assert!(loaded.resume("01.json").is_none());
+    assert!(loaded.resume("02.json").is_some());
+    assert!(loaded.resume("02.json").is_none());
+
+    // Written before matches was kept
+    let done: Done =
+        serde_json::from_str(r#"{"puzzle": "01.json", "solved": true, "message": ""}"#).unwrap();
+    assert_eq!(done.matches, None);
+}
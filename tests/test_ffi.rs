@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+/*
+ * Compile the C test program against the header and the cdylib, then run it
+ *
+ */
+#[test]
+fn test_01_ffi_c_program() {
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // The library is built next to the test binary, in deps
+    let deps = std::env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let program = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("test_singlenum");
+
+    let status = Command::new(std::env::var("CC").unwrap_or(String::from("cc")))
+        .arg(manifest.join("tests/ffi/test_singlenum.c"))
+        .arg("-I")
+        .arg(manifest.join("include"))
+        .arg("-L")
+        .arg(&deps)
+        .arg("-lsinglenum")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &deps)
+        .env("DYLD_LIBRARY_PATH", &deps)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
@@ -0,0 +1,86 @@
+use camino::Utf8PathBuf;
+use singlenum::components::table::core::Table;
+use singlenum::enums::Progress;
+use singlenum::fixture::{diff, expected, is_solution_file, solution_file, Mismatch};
+use singlenum::puzzle::load;
+
+#[test]
+fn test_01_fixture_files() {
+    let puzzle = Utf8PathBuf::from("puzzles/cat/extreme/01.json");
+    let solution = solution_file(&puzzle);
+    assert_eq!(solution, "puzzles/cat/extreme/01.solution.json");
+    assert!(is_solution_file(&solution));
+    assert!(!is_solution_file(&puzzle));
+    assert_eq!(
+        solution_file(&Utf8PathBuf::from("01.txt")),
+        "01.solution.txt"
+    );
+
+    assert!(expected(&Utf8PathBuf::from("puzzles/variants/xv_01.json"))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_01_fixture_diff() {
+    let puzzle = Utf8PathBuf::from("puzzles/cat/medium/puzzle_aa.json");
+    let solution = expected(&puzzle).unwrap().unwrap();
+    let mut table = load(&puzzle, 500).unwrap();
+
+    // Before solving, every square but the givens differs
+    let mismatches = diff(&table, &solution).unwrap();
+    let givens = table.squares.iter().filter(|x| x.value != 0).count();
+    assert_eq!(mismatches.len(), 81 - givens);
+    assert_eq!(
+        mismatches[0],
+        Mismatch {
+            square_id: 1,
+            line: 0,
+            column: 1,
+            expected: solution[1],
+            actual: 0
+        }
+    );
+    assert_eq!(
+        mismatches[0].to_string(),
+        format!("Line 1, column 2: expected {}, got empty", solution[1])
+    );
+
+    assert!(matches!(table.solve().unwrap(), Progress::Solved(_)));
+    assert_eq!(diff(&table, &solution).unwrap(), []);
+    assert!(diff(&table, &solution[1..]).is_err());
+}
+
+#[test]
+fn test_01_fixture_medium() {
+    // The bundled medium puzzles solve to their known solutions
+    for name in ["puzzle_aa", "puzzle_ab"] {
+        let puzzle = Utf8PathBuf::from(format!("puzzles/cat/medium/{name}.json"));
+        let solution = expected(&puzzle).unwrap().unwrap();
+        let mut table: Table = load(&puzzle, 500).unwrap();
+        table.solve().unwrap();
+        assert_eq!(diff(&table, &solution).unwrap(), [], "{name}");
+    }
+}
+
+#[test]
+fn test_01_fixture_cat() {
+    // Every bundled puzzle solves to its known solution, given enough attempts
+    for name in [
+        "extreme/01",
+        "extreme/02",
+        "hardest/puzzle_x_a",
+        "hardest/puzzle_x_b",
+        "medium/puzzle_aa",
+        "medium/puzzle_ab",
+    ] {
+        let puzzle = Utf8PathBuf::from(format!("puzzles/cat/{name}.json"));
+        let solution = expected(&puzzle).unwrap().unwrap();
+        let mut table: Table = load(&puzzle, 20000).unwrap();
+        assert!(
+            matches!(table.solve().unwrap(), Progress::Solved(_)),
+            "{name}"
+        );
+        assert_eq!(diff(&table, &solution).unwrap(), [], "{name}");
+    }
+}
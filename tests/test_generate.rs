@@ -0,0 +1,19 @@
+use singlenum::components::table::core::Table;
+use singlenum::enums::Progress;
+use singlenum::generate::generate;
+
+#[test]
+fn test_01_generate() {
+    let puzzle = generate(36, 500).unwrap();
+    assert_eq!(puzzle.len(), 81);
+    assert!(puzzle.iter().all(|x| *x <= 9));
+    assert!(puzzle.iter().filter(|x| **x != 0).count() >= 36);
+
+    // Solves without guessing, keeping the givens
+    let mut table = Table::new(puzzle.clone(), 500).unwrap();
+    assert!(matches!(table.solve().unwrap(), Progress::Solved(_)));
+    assert_eq!(table.get_snapshots_taken(), 0);
+    for (id, value) in puzzle.iter().enumerate().filter(|x| *x.1 != 0) {
+        assert_eq!(table.squares[id].value, *value);
+    }
+}
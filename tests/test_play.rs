@@ -0,0 +1,104 @@
+use singlenum::components::table::core::Table;
+use singlenum::play::Game;
+
+mod common;
+
+/*
+ * The near-solved grid with squares 18, 40 and 80 empty, they should be 1, 6
+ * and 1.
+ */
+fn game() -> Game {
+    let mut configuration = common::near_solved();
+    configuration[40] = 0;
+    configuration[80] = 0;
+    Game::new(Table::new(configuration, 100).unwrap()).unwrap()
+}
+
+#[test]
+fn test_01_move_cursor() {
+    let mut game = game();
+    game.move_cursor(-1, -1);
+    assert_eq!(game.cursor, 80);
+    game.move_cursor(1, 2);
+    assert_eq!(game.cursor, 1);
+}
+
+#[test]
+fn test_01_enter_and_undo() {
+    let mut game = game();
+
+    // Givens can not be changed
+    game.enter(3);
+    assert_eq!(game.entries[0], 0);
+
+    game.cursor = 18;
+    game.pencil = true;
+    game.enter(1);
+    game.enter(2);
+    game.enter(1);
+    assert_eq!(game.marks[18], [2].to_vec());
+
+    game.pencil = false;
+    game.enter(7);
+    assert_eq!(game.entries[18], 7);
+    assert!(game.marks[18].is_empty());
+
+    game.undo();
+    assert_eq!(game.entries[18], 0);
+    assert_eq!(game.marks[18], [2].to_vec());
+}
+
+#[test]
+fn test_01_check() {
+    let mut game = game();
+    game.cursor = 18;
+    game.enter(1);
+    game.cursor = 40;
+    game.enter(5);
+    game.check();
+    assert_eq!(game.wrong, [40].to_vec());
+
+    game.enter(6);
+    game.cursor = 80;
+    game.enter(1);
+    assert_eq!(game.message, "Solved, well done!");
+}
+
+#[test]
+fn test_01_hint() {
+    let mut game = game();
+    let hint = game.hint().unwrap().unwrap();
+    assert!([(18, 1), (40, 6), (80, 1)].contains(&(hint.square_id, hint.value)));
+    assert!(game.message.starts_with("Hint: Look at"));
+    assert_eq!(game.cursor, 0);
+
+    // Asking again gives more detail, at last the square
+    game.hint().unwrap();
+    game.hint().unwrap();
+    assert!(game.message.starts_with("Hint: Line"));
+    assert_eq!(game.cursor, hint.square_id);
+    assert_eq!(game.entries[hint.square_id], 0);
+
+    // No hint as long as there are mistakes
+    game.cursor = 40;
+    game.enter(5);
+    assert!(game.hint().unwrap().is_none());
+}
+
+#[test]
+fn test_01_hint_conflict() {
+    let mut game = game();
+
+    // Unsolved, nothing to check against, a conflict still refuses a hint
+    let mut unsolved = Game::new(Table::new(game.values(), 1).unwrap()).unwrap();
+    unsolved.check();
+    assert!(unsolved.wrong.is_empty());
+    unsolved.cursor = 18;
+    unsolved.enter(8);
+    assert!(unsolved.hint().unwrap().is_none());
+    assert_eq!(unsolved.message, "Entries conflict, fix them first");
+
+    game.cursor = 18;
+    game.enter(1);
+    assert!(game.hint().unwrap().is_some());
+}
@@ -0,0 +1,37 @@
+use singlenum::components::table::core::Table;
+use singlenum::enums::Progress;
+use singlenum::record::{grid, Record};
+
+mod common;
+
+#[test]
+fn test_01_record() {
+    let configuration = common::near_solved();
+    let mut table = Table::new(configuration, 500).unwrap();
+    assert_eq!(&grid(&table)[18..27], ".64379528");
+
+    let progress = table.solve().unwrap();
+    let record = Record::new("01.json", &table, &progress);
+    assert_eq!(record.outcome, "solved");
+    assert_eq!(&record.grid[18..27], "164379528");
+    assert_eq!(record.snapshots, 0);
+    assert_eq!(
+        record.line(),
+        format!(
+            "{} solved [iterations: {}, snapshots: 0, rollbacks: 0]",
+            record.grid, record.iterations
+        )
+    );
+
+    let json = serde_json::to_value(&record).unwrap();
+    assert_eq!(json["puzzle"], "01.json");
+    assert_eq!(json["grid"].as_str().unwrap().len(), 81);
+}
+
+#[test]
+fn test_01_record_outcome() {
+    let table = Table::new([0; 81].to_vec(), 1).unwrap();
+    let record = Record::new("-", &table, &Progress::TimedOut(String::new()));
+    assert_eq!(record.outcome, "timed-out");
+    assert_eq!(record.grid, ".".repeat(81));
+}
@@ -0,0 +1,141 @@
+use serde_json::{json, Value};
+use singlenum::serve::{handle, Limits, Service};
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::time::Duration;
+
+mod common;
+
+use common::PUZZLE;
+
+fn post(address: SocketAddr, url: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "POST {url} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let status = response[9..12].parse().unwrap();
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn test_01_serve_handle() {
+    let limits = Limits::default();
+    let body = json!({ "puzzle": PUZZLE }).to_string();
+
+    let (status, solve) = handle("POST", "/solve", &body, &limits);
+    assert_eq!(status, 200);
+    assert_eq!(solve["outcome"], "solved");
+    assert!(!solve["grid"].as_str().unwrap().contains('.'));
+
+    let (status, grade) = handle("POST", "/grade", &body, &limits);
+    assert_eq!(status, 200);
+    assert_eq!(grade["difficulty"], "Easy");
+
+    let (status, hint) = handle("POST", "/hint", &body, &limits);
+    assert_eq!(status, 200);
+    let square = hint["hint"]["square"].as_u64().unwrap() as usize;
+    assert_eq!(&PUZZLE[square..square + 1], ".");
+
+    let (status, valid) = handle("POST", "/validate", &body, &limits);
+    assert_eq!((status, &valid["valid"]), (200, &json!(true)));
+    let conflict = json!({ "puzzle": PUZZLE.replacen('.', "2", 1) }).to_string();
+    let (status, valid) = handle("POST", "/validate", &conflict, &limits);
+    assert_eq!((status, &valid["valid"]), (200, &json!(false)));
+
+    let (status, generated) = handle("POST", "/generate", r#"{"givens": 40}"#, &limits);
+    assert_eq!(status, 200);
+    assert_eq!(generated["puzzle"].as_array().unwrap().len(), 81);
+}
+
+#[test]
+fn test_01_serve_handle_errors() {
+    let limits = Limits::default();
+    assert_eq!(handle("GET", "/solve", "", &limits).0, 405);
+    assert_eq!(handle("POST", "/unknown", "", &limits).0, 404);
+
+    let (status, error) = handle("POST", "/solve", "{", &limits);
+    assert_eq!(status, 400);
+    assert!(error["error"].as_str().unwrap().starts_with("Invalid JSON"));
+    assert_eq!(handle("POST", "/solve", "{}", &limits).0, 400);
+    assert_eq!(
+        handle("POST", "/solve", r#"{"puzzle": "123"}"#, &limits).0,
+        400
+    );
+    assert_eq!(
+        handle("POST", "/generate", r#"{"givens": 90}"#, &limits).0,
+        400
+    );
+
+    // Out of time before a full grid is the generator failing, not the request
+    let limits = Limits {
+        timeout: Duration::ZERO,
+        ..Limits::default()
+    };
+    let (status, error) = handle("POST", "/generate", r#"{"givens": 40}"#, &limits);
+    assert_eq!(status, 500);
+    assert_eq!(error["error"], "Stopped before a full grid was found");
+}
+
+#[test]
+fn test_01_serve_http() {
+    let limits = Limits {
+        max_body: 1024,
+        ..Limits::default()
+    };
+    let service = Service::bind("127.0.0.1:0", 4, limits).unwrap();
+    let address = service.address().unwrap();
+
+    // Handled side by side
+    let body = json!({ "puzzle": PUZZLE }).to_string();
+    let clients: Vec<_> = (0..8)
+        .map(|_| {
+            let body = body.clone();
+            std::thread::spawn(move || post(address, "/solve", &body))
+        })
+        .collect();
+    for client in clients {
+        let (status, record) = client.join().unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(record["outcome"], "solved");
+    }
+
+    let (status, error) = post(address, "/solve", &" ".repeat(2048));
+    assert_eq!(status, 413);
+    assert!(error["error"].is_string());
+
+    service.stop();
+}
+
+#[test]
+fn test_01_serve_conflicting_givens() {
+    let service = Service::bind("127.0.0.1:0", 1, Limits::default()).unwrap();
+    let address = service.address().unwrap();
+
+    // Refused, and the one thread is still there for the next request
+    let conflict = json!({ "puzzle": format!("11{}", ".".repeat(79)) }).to_string();
+    for url in ["/solve", "/grade", "/hint"] {
+        let (status, error) = post(address, url, &conflict);
+        assert_eq!(status, 400);
+        assert_eq!(error["error"], "Givens conflict");
+    }
+
+    // The givens hold but leave no value for the last square of line 1, the
+    // solver fails and the thread serves on
+    let dead = format!("12345678.{}9{}", ".".repeat(8), ".".repeat(63));
+    let (status, error) = post(address, "/solve", &json!({ "puzzle": dead }).to_string());
+    assert_eq!(status, 500);
+    assert!(error["error"].is_string());
+
+    let (status, record) = post(address, "/solve", &json!({ "puzzle": PUZZLE }).to_string());
+    assert_eq!(status, 200);
+    assert_eq!(record["outcome"], "solved");
+
+    service.stop();
+}
@@ -0,0 +1,75 @@
+use serde_json::Value;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+mod common;
+
+use common::PUZZLE;
+
+/*
+ * Solve puzzles from stdin, the output and whether the run succeeded
+ *
+ */
+fn solve(input: &str, output: &str) -> (String, bool) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_singlenum"))
+        .args(["solve", "-", "--output", output])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let result = child.wait_with_output().unwrap();
+    (
+        String::from_utf8(result.stdout).unwrap(),
+        result.status.success(),
+    )
+}
+
+/*
+ * A puzzle that fails is an error record, the stream carries on past it.
+ */
+#[test]
+fn test_01_stdin_error_records() {
+    // Givens that hold, but square 0 has no value left
+    let unsolvable = format!(".12345678{}{}", "9", ".".repeat(71));
+    let mut out_of_range = vec!["0"; 81];
+    out_of_range[0] = "10";
+    let out_of_range = format!("[{}]", out_of_range.join(","));
+    let input = [PUZZLE, &unsolvable, &out_of_range, PUZZLE].join("\n");
+
+    let (output, success) = solve(&input, "ndjson");
+    assert!(!success);
+    let records: Vec<Value> = output
+        .lines()
+        .map(|x| serde_json::from_str(x).unwrap())
+        .collect();
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0]["outcome"], "solved");
+    assert_eq!(records[1]["puzzle"], "stdin:2");
+    assert_eq!(records[1]["outcome"], "error");
+    assert_eq!(
+        records[1]["error"],
+        "No solution, no guess left to roll back"
+    );
+    assert_eq!(records[2]["outcome"], "error");
+    assert!(records[2]["error"]
+        .as_str()
+        .unwrap()
+        .ends_with("Square 0 of grid (0, 0) is 10, squares are 0 to 9"));
+    assert_eq!(records[3]["puzzle"], "stdin:4");
+    assert_eq!(records[3]["outcome"], "solved");
+
+    let (output, _) = solve(&input, "line");
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(
+        lines[1],
+        "stdin:2 error No solution, no guess left to roll back"
+    );
+}
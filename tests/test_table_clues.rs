@@ -0,0 +1,77 @@
+use singlenum::components::clue::Clue;
+use singlenum::components::constraint::Constraint;
+use singlenum::components::table::core::Table;
+use singlenum::enums::Side;
+
+#[test]
+fn test_01_little_killer() {
+    let clue = Clue::little_killer(1, "down-right", 40).unwrap();
+    assert_eq!(clue.side, Side::TOP);
+    assert_eq!(clue.index, 1);
+    assert_eq!(
+        clue.get_square_ids(),
+        [1, 11, 21, 31, 41, 51, 61, 71].to_vec()
+    );
+    assert_eq!(clue.label(), "40↘");
+
+    let clue = Clue::little_killer(45, "up-right", 26).unwrap();
+    assert_eq!(clue.side, Side::LEFT);
+    assert_eq!(clue.index, 5);
+    assert_eq!(clue.get_square_ids(), [45, 37, 29, 21, 13, 5].to_vec());
+
+    assert!(Clue::little_killer(40, "down-right", 10).is_err());
+    assert!(Clue::little_killer(1, "down", 10).is_err());
+}
+
+#[test]
+fn test_01_clue_valid() {
+    let sandwich = Clue::sandwich_line(0, 5).unwrap();
+    assert!(sandwich.valid(&[1, 2, 3, 9, 0, 0, 0, 0, 0]));
+    assert!(!sandwich.valid(&[1, 2, 4, 9, 0, 0, 0, 0, 0]));
+    assert!(!sandwich.valid(&[1, 4, 0, 9, 0, 0, 0, 0, 0]));
+    assert!(sandwich.valid(&[1, 0, 0, 0, 0, 0, 0, 0, 0]));
+
+    let little_killer = Clue::little_killer(6, "down-right", 10).unwrap();
+    assert!(little_killer.valid(&[2, 3, 5]));
+    assert!(!little_killer.valid(&[2, 3, 4]));
+    assert!(!little_killer.valid(&[5, 5, 0]));
+}
+
+/*
+ * An empty table with a sandwich of 0 on the first line. 1 and 9 must be
+ * next to each other, so a square can only be 1 or 9 if a neighbour can be
+ * the other.
+ */
+#[test]
+fn test_01_update_sandwich_potentials() {
+    let mut configuration: Vec<usize> = [0; 81].to_vec();
+    configuration[0] = 1;
+
+    let mut table = Table::new(configuration, 1).unwrap();
+    table
+        .add_constraint(Constraint::Clue(Clue::sandwich_line(0, 0).unwrap()))
+        .unwrap();
+    table.update().unwrap();
+    assert_eq!(table.squares[1].potentials, [9].to_vec());
+    assert!(!table.squares[2].potentials.contains(&9));
+}
+
+/*
+ * An empty table with a little killer of 3 on a diagonal of two squares.
+ */
+#[test]
+fn test_01_update_little_killer_potentials() {
+    let mut table = Table::new([0; 81].to_vec(), 1).unwrap();
+    table
+        .add_constraint(Constraint::Clue(
+            Clue::little_killer(7, "down-right", 3).unwrap(),
+        ))
+        .unwrap();
+    table.update().unwrap();
+
+    for square_id in [7, 17] {
+        let mut potentials = table.squares[square_id].potentials.clone();
+        potentials.sort();
+        assert_eq!(potentials, [1, 2].to_vec());
+    }
+}
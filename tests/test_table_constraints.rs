@@ -0,0 +1,107 @@
+use singlenum::components::cage::Cage;
+use singlenum::components::constraint::{diagonal, Constraint};
+use singlenum::components::table::core::Table;
+use singlenum::enums::Rule;
+
+fn all() -> Vec<usize> {
+    (1..=9).collect()
+}
+
+#[test]
+fn test_01_diagonal() {
+    assert_eq!(
+        diagonal(false),
+        [0, 10, 20, 30, 40, 50, 60, 70, 80].to_vec()
+    );
+    assert_eq!(diagonal(true), [8, 16, 24, 32, 40, 48, 56, 64, 72].to_vec());
+}
+
+#[test]
+fn test_01_cage_new() {
+    assert!(Cage::new(10, [0, 1].to_vec()).is_ok());
+    assert!(Cage::new(10, Vec::new()).is_err());
+    assert!(Cage::new(10, [0, 0].to_vec()).is_err());
+    assert!(Cage::new(10, [80, 81].to_vec()).is_err());
+}
+
+#[test]
+fn test_01_prune_cage() {
+    let cage = Cage::new(4, [0, 1].to_vec()).unwrap();
+    let pruned = cage.prune(&[all(), all()]);
+    assert_eq!(pruned[0], [1, 3].to_vec());
+    assert_eq!(pruned[1], [1, 3].to_vec());
+
+    // A set square takes its value from the other squares of the cage
+    let cage = Cage::new(10, [0, 1, 2].to_vec()).unwrap();
+    let pruned = cage.prune(&[[5].to_vec(), all(), all()]);
+    assert_eq!(pruned[1], [1, 2, 3, 4].to_vec());
+}
+
+#[test]
+fn test_01_cage_valid() {
+    let cage = Cage::new(10, [0, 1, 2].to_vec()).unwrap();
+    assert!(cage.valid(&[2, 3, 5]));
+    assert!(cage.valid(&[2, 3, 0]));
+    assert!(!cage.valid(&[2, 3, 4]));
+    assert!(!cage.valid(&[5, 5, 0]));
+    assert!(!cage.valid(&[6, 4, 0]));
+}
+
+/*
+ * An empty table but a 5 in the top-left corner. With the main diagonal as
+ * a region no other square on the diagonal may hold a 5.
+ */
+#[test]
+fn test_01_update_region_potentials() {
+    let mut configuration = [0; 81].to_vec();
+    configuration[0] = 5;
+
+    let mut table = Table::new(configuration, 1).unwrap();
+    table
+        .add_constraint(Constraint::Region(diagonal(false)))
+        .unwrap();
+    table.update().unwrap();
+
+    for square_id in [30, 40, 50, 60, 70, 80] {
+        assert!(!table.squares[square_id].potentials.contains(&5));
+    }
+    assert!(table.squares[44].potentials.contains(&5));
+}
+
+#[test]
+fn test_01_update_cage_potentials() {
+    let mut table = Table::new([0; 81].to_vec(), 1).unwrap();
+    table
+        .add_constraint(Constraint::Cage(Cage::new(3, [0, 1].to_vec()).unwrap()))
+        .unwrap();
+    table.update().unwrap();
+
+    for square_id in [0, 1] {
+        let mut potentials = table.squares[square_id].potentials.clone();
+        potentials.sort();
+        assert_eq!(potentials, [1, 2].to_vec());
+    }
+}
+
+#[test]
+fn test_01_add_constraint() {
+    let mut table = Table::new([0; 81].to_vec(), 1).unwrap();
+    assert!(table
+        .add_constraint(Constraint::Rule(Rule::ANTIKING))
+        .is_ok());
+    assert!(table
+        .add_constraint(Constraint::Region([0, 1, 2].to_vec()))
+        .is_err());
+    assert!(table
+        .add_constraint(Constraint::Region([0, 1, 2, 3, 4, 5, 6, 7, 7].to_vec()))
+        .is_err());
+
+    let mut table = Table::new_multi(
+        [((0, 0), [0; 81].to_vec()), ((6, 6), [0; 81].to_vec())].to_vec(),
+        1,
+    )
+    .unwrap();
+    assert!(table
+        .add_constraint(Constraint::Rule(Rule::ANTIKING))
+        .is_err());
+}
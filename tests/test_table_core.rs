@@ -1,4 +1,5 @@
 use singlenum::components::table::core::Table;
+use singlenum::enums::{Progress, SetKind, Step, Technique};
 
 /*
  * ╔═══╤═══╤═══╦═══╤═══╤═══╦═══╤═══╤═══╗
@@ -18,7 +19,7 @@ fn test_01_engine_box_one_left() {
         6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
     ]
     .to_vec();
-    let mut table = Table::new(configuration, 1);
+    let mut table = Table::new(configuration, 1).unwrap();
     table.update_abox().unwrap();
 
     let result = table.engine_box_one_left().unwrap();
@@ -45,7 +46,7 @@ fn test_02_engine_box_one_left() {
         6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
     ]
     .to_vec();
-    let mut table = Table::new(configuration, 1);
+    let mut table = Table::new(configuration, 1).unwrap();
     table.update_abox().unwrap();
 
     let result = table.engine_box_one_left().unwrap();
@@ -71,7 +72,7 @@ fn test_01_engine_line_one_left() {
         6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
     ]
     .to_vec();
-    let mut table = Table::new(configuration, 1);
+    let mut table = Table::new(configuration, 1).unwrap();
     table.update_line().unwrap();
 
     let result = table.engine_line_one_left().unwrap();
@@ -98,7 +99,7 @@ fn test_02_engine_line_one_left() {
         6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
     ]
     .to_vec();
-    let mut table = Table::new(configuration, 1);
+    let mut table = Table::new(configuration, 1).unwrap();
     table.update_line().unwrap();
 
     let result = table.engine_line_one_left().unwrap();
@@ -135,7 +136,7 @@ fn test_01_engine_column_one_left() {
         6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
     ]
     .to_vec();
-    let mut table = Table::new(configuration, 1);
+    let mut table = Table::new(configuration, 1).unwrap();
     table.update_column().unwrap();
 
     let result = table.engine_column_one_left().unwrap();
@@ -173,7 +174,7 @@ fn test_02_engine_column_one_left() {
         6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
     ]
     .to_vec();
-    let mut table = Table::new(configuration, 1);
+    let mut table = Table::new(configuration, 1).unwrap();
     table.update_column().unwrap();
 
     let result = table.engine_column_one_left().unwrap();
@@ -210,7 +211,7 @@ fn test_01_engine_only_one_possible() {
         6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
     ]
     .to_vec();
-    let mut table = Table::new(configuration, 1);
+    let mut table = Table::new(configuration, 1).unwrap();
     table.squares[40].potentials = [6].to_vec();
 
     let result = table.engine_only_one_possible().unwrap();
@@ -248,7 +249,7 @@ fn test_02_engine_only_one_possible() {
         6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
     ]
     .to_vec();
-    let mut table = Table::new(configuration, 1);
+    let mut table = Table::new(configuration, 1).unwrap();
     table.squares[39].potentials = [6, 2].to_vec();
     table.squares[40].potentials = [6, 2].to_vec();
 
@@ -298,10 +299,81 @@ fn test_01_engine_box() {
         0, 0, 0, 2, 6, 0, 0, 9, 7, 1, 5, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2,
     ]
     .to_vec();
-    let mut table = Table::new(configuration, 1);
+    let mut table = Table::new(configuration, 1).unwrap();
 
     table.update().unwrap();
     let result = table.engine_box().unwrap();
     assert!(result);
     assert_eq!(table.squares[29].value, 9_usize);
 }
+
+/*
+ * Squares record where their value came from, given or set by the engine.
+ */
+#[test]
+fn test_01_square_kind() {
+    let configuration: Vec<usize> = [
+        8, 5, 9, 6, 1, 2, 4, 3, 7, 7, 2, 3, 8, 5, 4, 1, 6, 9, 0, 6, 4, 3, 7, 9, 5, 2, 8, 9, 8, 6,
+        1, 4, 7, 3, 5, 2, 3, 7, 5, 2, 6, 8, 9, 1, 4, 2, 4, 1, 5, 9, 3, 7, 8, 6, 4, 3, 2, 9, 8, 1,
+        6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
+    ]
+    .to_vec();
+    let mut table = Table::new(configuration, 1).unwrap();
+    assert_eq!(table.squares[0].kind, Some(SetKind::GIVEN));
+    assert_eq!(table.squares[18].kind, None);
+
+    table.update_line().unwrap();
+    table.engine_line_one_left().unwrap();
+    assert_eq!(table.squares[18].kind, Some(SetKind::NORMAL));
+}
+
+/*
+ * One step sets the only empty square, the next finds the puzzle solved.
+ */
+#[test]
+fn test_01_step() {
+    let configuration: Vec<usize> = [
+        8, 5, 9, 6, 1, 2, 4, 3, 7, 7, 2, 3, 8, 5, 4, 1, 6, 9, 0, 6, 4, 3, 7, 9, 5, 2, 8, 9, 8, 6,
+        1, 4, 7, 3, 5, 2, 3, 7, 5, 2, 6, 8, 9, 1, 4, 2, 4, 1, 5, 9, 3, 7, 8, 6, 4, 3, 2, 9, 8, 1,
+        6, 7, 5, 6, 1, 7, 4, 2, 5, 8, 9, 3, 5, 9, 8, 7, 3, 6, 2, 4, 1,
+    ]
+    .to_vec();
+    let mut table = Table::new(configuration, 10).unwrap();
+
+    assert!(matches!(
+        table.step().unwrap(),
+        Step::Deduced(Technique::LINEONELEFT)
+    ));
+    assert_eq!(table.squares[18].value, 1);
+    assert!(matches!(
+        table.step().unwrap(),
+        Step::Done(Progress::Solved(_))
+    ));
+}
+
+/*
+ * A guess is made on an empty square, never over a given.
+ */
+#[test]
+fn test_01_guess_keeps_givens() {
+    let line = "4.2753...5..89.4...6.1...58.49.123.562.9...4..3147596.......57.3.....8.9178560.3.";
+    let configuration: Vec<usize> = line
+        .chars()
+        .map(|x| x.to_digit(10).unwrap_or(0) as usize)
+        .collect();
+
+    for (guess, guessed) in [
+        (Table::qualified_guess as fn(&mut Table) -> _, true),
+        (Table::incompetent_guess, false),
+    ] {
+        let mut table = Table::new(configuration.clone(), 10).unwrap();
+        table.update().unwrap();
+        assert_eq!(guess(&mut table).unwrap(), guessed);
+
+        let set: Vec<usize> = (0..81)
+            .filter(|x| table.squares[*x].value != configuration[*x])
+            .collect();
+        assert_eq!(set.len(), guessed as usize);
+        assert!(set.iter().all(|x| configuration[*x] == 0));
+    }
+}
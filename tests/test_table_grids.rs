@@ -0,0 +1,96 @@
+use singlenum::components::table::core::Table;
+use singlenum::components::table::draw::{render_candidates, render_table};
+
+fn samurai() -> Vec<((usize, usize), Vec<usize>)> {
+    [(0, 0), (0, 12), (6, 6), (12, 0), (12, 12)]
+        .iter()
+        .map(|x| (*x, [0; 81].to_vec()))
+        .collect()
+}
+
+#[test]
+fn test_01_new_classic() {
+    let table = Table::new([0; 81].to_vec(), 1).unwrap();
+    assert_eq!(table.squares.len(), 81);
+    assert_eq!(table.get_grids().len(), 1);
+    assert_eq!(table.squares[40].line_ids, [4].to_vec());
+    assert_eq!(table.squares[40].column_ids, [4].to_vec());
+    assert_eq!(table.squares[40].abox_id, 4);
+
+    assert!(Table::new([0; 80].to_vec(), 1).is_err());
+}
+
+#[test]
+fn test_01_new_multi_samurai() {
+    let table = Table::new_multi(samurai(), 1).unwrap();
+    assert_eq!(table.squares.len(), 5 * 81 - 4 * 9);
+    assert_eq!(table.get_grids().len(), 5);
+
+    // Bottom right square of the top left grid is shared with the center grid
+    let top_left = table.get_grids()[0].get_square_id(8, 8);
+    let center = table.get_grids()[2].get_square_id(2, 2);
+    assert_eq!(top_left, center);
+    assert_eq!(table.squares[top_left].line_ids.len(), 2);
+    assert_eq!(table.squares[top_left].column_ids.len(), 2);
+
+    // Top left square is only part of one grid
+    assert_eq!(table.squares[0].line_ids.len(), 1);
+}
+
+#[test]
+fn test_01_new_multi_errors() {
+    let misaligned = [((0, 0), [0; 81].to_vec()), ((4, 4), [0; 81].to_vec())].to_vec();
+    assert!(Table::new_multi(misaligned, 1).is_err());
+
+    let mut a = [0; 81].to_vec();
+    let mut b = [0; 81].to_vec();
+    a[80] = 1;
+    b[20] = 2;
+    let conflict = [((0, 0), a), ((6, 6), b)].to_vec();
+    assert!(Table::new_multi(conflict, 1).is_err());
+
+    let mut c = [0; 81].to_vec();
+    c[40] = 10;
+    let error = Table::new_multi([((0, 0), c)].to_vec(), 1).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Square 40 of grid (0, 0) is 10, squares are 0 to 9"
+    );
+}
+
+/*
+ * A value set in the shared box is taken on the lines of both grids.
+ */
+#[test]
+fn test_01_update_shared_potentials() {
+    let mut grids = samurai();
+    grids[0].1[80] = 5;
+
+    let mut table = Table::new_multi(grids, 1).unwrap();
+    table.update().unwrap();
+
+    let center = table.get_grids()[2].get_square_id(2, 8);
+    assert!(!table.squares[center].potentials.contains(&5));
+}
+
+/*
+ * Candidates of several grids are drawn on the canvas, a mini-grid per square.
+ */
+#[test]
+fn test_01_render_candidates_samurai() {
+    let mut grids = samurai();
+    grids[0].1[0] = 5;
+
+    let mut table = Table::new_multi(grids, 1).unwrap();
+    table.update().unwrap();
+
+    let candidates = render_candidates(&table, false);
+    let lines: Vec<&str> = candidates.lines().collect();
+    assert_eq!(lines.len(), 22 + 21 * 3);
+    assert_eq!(render_table(&table, false).lines().count(), 22 + 21);
+    assert!(lines[1].starts_with("║       │ 1 2 3 │"));
+    assert!(lines[2].starts_with("║   5   │ 4   6 │"));
+
+    // Top right grid, a square without any value taken
+    assert!(lines[2].ends_with("│ 4 5 6 ║"));
+}
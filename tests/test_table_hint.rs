@@ -0,0 +1,72 @@
+use singlenum::components::table::core::Table;
+use singlenum::enums::{Container, HintLevel, Technique};
+
+mod common;
+
+#[test]
+fn test_01_hint() {
+    let table = Table::new(common::near_solved(), 1).unwrap();
+    let hint = table.hint().unwrap().unwrap();
+
+    assert_eq!(hint.container, Container::LINE);
+    assert_eq!(hint.container_id, 2);
+    assert_eq!(hint.technique, Technique::LINEONELEFT);
+    assert_eq!((hint.square_id, hint.value), (18, 1));
+
+    // The table is not changed
+    assert_eq!(table.squares[18].value, 0);
+}
+
+#[test]
+fn test_01_hint_describe() {
+    let hint = Table::new(common::near_solved(), 1)
+        .unwrap()
+        .hint()
+        .unwrap()
+        .unwrap();
+    assert_eq!(hint.describe(&HintLevel::CONTAINER), "Look at line 3");
+    assert_eq!(
+        hint.describe(&HintLevel::TECHNIQUE),
+        "Look at line 3, only one value is left in the line"
+    );
+    assert_eq!(
+        hint.describe(&HintLevel::ANSWER),
+        "Line 3, column 1 is 1, only one value is left in the line"
+    );
+}
+
+/*
+ * An empty table has no logical step, only guesses.
+ */
+#[test]
+fn test_01_hint_none() {
+    let table = Table::new([0; 81].to_vec(), 1).unwrap();
+    assert!(table.hint().unwrap().is_none());
+}
+
+/*
+ * A value that is the only one possible in a square is found in the square,
+ * not in its box.
+ */
+#[test]
+fn test_01_hint_only_one_possible() {
+    let puzzle =
+        "..2753...5..89.4...6.1...58.49.123.562.9...4..3147596.......57.3.....8.9178560.3.";
+    let configuration: Vec<usize> = puzzle
+        .chars()
+        .map(|x| x.to_digit(10).unwrap_or(0) as usize)
+        .collect();
+    let hint = Table::new(configuration, 1)
+        .unwrap()
+        .hint()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(hint.technique, Technique::ONLYONEPOSSIBLE);
+    assert_eq!(hint.container, Container::SQUARE);
+    assert_eq!(hint.container_id, hint.square_id);
+    assert_eq!(
+        hint.describe(&HintLevel::CONTAINER),
+        format!("Look at line {}, column {}", hint.line + 1, hint.column + 1)
+    );
+}
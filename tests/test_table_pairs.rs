@@ -0,0 +1,86 @@
+use singlenum::components::constraint::Constraint;
+use singlenum::components::pair::Pair;
+use singlenum::components::table::core::Table;
+use singlenum::enums::PairKind;
+
+#[test]
+fn test_01_pair_kind_holds() {
+    assert!(PairKind::WHITE.holds(4, 5));
+    assert!(!PairKind::WHITE.holds(4, 6));
+    assert!(PairKind::BLACK.holds(3, 6));
+    assert!(!PairKind::BLACK.holds(3, 7));
+    assert!(PairKind::X.holds(1, 9));
+    assert!(PairKind::V.holds(2, 3));
+}
+
+#[test]
+fn test_01_pair_new() {
+    assert!(Pair::new(PairKind::X, [0, 1].to_vec()).is_ok());
+    assert!(Pair::new(PairKind::X, [9, 0].to_vec()).is_ok());
+    assert!(Pair::new(PairKind::X, [8, 9].to_vec()).is_err());
+    assert!(Pair::new(PairKind::X, [0, 2].to_vec()).is_err());
+}
+
+/*
+ * An empty table with a V between square 0 and 1, where square 1 is 4.
+ * Square 0 must be 1.
+ */
+#[test]
+fn test_01_update_pair_potentials() {
+    let mut configuration: Vec<usize> = [0; 81].to_vec();
+    configuration[1] = 4;
+
+    let mut table = Table::new(configuration, 1).unwrap();
+    table
+        .add_constraint(Constraint::Pair(
+            Pair::new(PairKind::V, [0, 1].to_vec()).unwrap(),
+        ))
+        .unwrap();
+    table.update().unwrap();
+    assert_eq!(table.squares[0].potentials, [1].to_vec());
+}
+
+/*
+ * An empty table with negative X, where square 1 is 4. No adjacent square
+ * may be 6.
+ */
+#[test]
+fn test_01_update_negative_potentials() {
+    let mut configuration: Vec<usize> = [0; 81].to_vec();
+    configuration[1] = 4;
+
+    let mut table = Table::new(configuration, 1).unwrap();
+    table
+        .add_constraint(Constraint::Negative(PairKind::X))
+        .unwrap();
+    table.update().unwrap();
+    assert!(!table.squares[0].potentials.contains(&6));
+    assert!(!table.squares[10].potentials.contains(&6));
+    assert!(table.squares[9].potentials.contains(&6));
+}
+
+/*
+ * An empty table with negative white and a black dot between square 0 and 1,
+ * where square 1 is 2. The dot exempts the pair, square 0 may be 1.
+ */
+#[test]
+fn test_01_negative_exempts_marked_pairs() {
+    let mut configuration: Vec<usize> = [0; 81].to_vec();
+    configuration[1] = 2;
+
+    let mut table = Table::new(configuration, 1).unwrap();
+    table
+        .add_constraint(Constraint::Pair(
+            Pair::new(PairKind::BLACK, [0, 1].to_vec()).unwrap(),
+        ))
+        .unwrap();
+    table
+        .add_constraint(Constraint::Negative(PairKind::WHITE))
+        .unwrap();
+    table.update().unwrap();
+    assert!(table.squares[0].potentials.contains(&1));
+    assert!(!table.squares[10].potentials.contains(&1));
+
+    table.squares[0].value = 1;
+    assert!(table.validate().unwrap());
+}
@@ -0,0 +1,68 @@
+use singlenum::components::constraint::Constraint;
+use singlenum::components::path::Path;
+use singlenum::components::table::core::Table;
+use singlenum::enums::PathKind;
+
+fn all() -> Vec<usize> {
+    (1..=9).collect()
+}
+
+#[test]
+fn test_01_prune_thermo() {
+    let path = Path::new(PathKind::THERMO, [0, 1, 2].to_vec()).unwrap();
+    let pruned = path.prune(&[all(), all(), all()]);
+    assert_eq!(pruned[0], (1..=7).collect::<Vec<usize>>());
+    assert_eq!(pruned[1], (2..=8).collect::<Vec<usize>>());
+    assert_eq!(pruned[2], (3..=9).collect::<Vec<usize>>());
+}
+
+#[test]
+fn test_01_prune_arrow() {
+    let path = Path::new(PathKind::ARROW, [0, 1, 2].to_vec()).unwrap();
+    let pruned = path.prune(&[[4].to_vec(), all(), all()]);
+    assert_eq!(pruned[0], [4].to_vec());
+    assert_eq!(pruned[1], [1, 2, 3].to_vec());
+    assert_eq!(pruned[2], [1, 2, 3].to_vec());
+}
+
+#[test]
+fn test_01_prune_palindrome() {
+    let path = Path::new(PathKind::PALINDROME, [0, 10, 2].to_vec()).unwrap();
+    let pruned = path.prune(&[[1, 2, 3].to_vec(), all(), [3, 4].to_vec()]);
+    assert_eq!(pruned[0], [3].to_vec());
+    assert_eq!(pruned[1], all());
+    assert_eq!(pruned[2], [3].to_vec());
+}
+
+#[test]
+fn test_01_path_valid() {
+    let thermo = Path::new(PathKind::THERMO, [0, 1, 2].to_vec()).unwrap();
+    assert!(thermo.valid(&[1, 0, 3]));
+    assert!(!thermo.valid(&[2, 0, 3]));
+
+    let arrow = Path::new(PathKind::ARROW, [0, 1, 2].to_vec()).unwrap();
+    assert!(arrow.valid(&[9, 4, 5]));
+    assert!(!arrow.valid(&[9, 4, 4]));
+    assert!(!arrow.valid(&[5, 5, 0]));
+
+    let palindrome = Path::new(PathKind::PALINDROME, [0, 10, 2].to_vec()).unwrap();
+    assert!(palindrome.valid(&[3, 0, 3]));
+    assert!(!palindrome.valid(&[3, 0, 4]));
+}
+
+/*
+ * An empty table with a thermo along the first line. The bulb must be 1
+ * and the tip must be 9.
+ */
+#[test]
+fn test_01_update_path_potentials() {
+    let mut table = Table::new([0; 81].to_vec(), 1).unwrap();
+    table
+        .add_constraint(Constraint::Path(
+            Path::new(PathKind::THERMO, (0..9).collect()).unwrap(),
+        ))
+        .unwrap();
+    table.update().unwrap();
+    assert_eq!(table.squares[0].potentials, [1].to_vec());
+    assert_eq!(table.squares[8].potentials, [9].to_vec());
+}
@@ -0,0 +1,84 @@
+use singlenum::components::cage::Cage;
+use singlenum::components::constraint::Constraint;
+use singlenum::components::pair::Pair;
+use singlenum::components::table::core::Table;
+use singlenum::components::table::render::{Accessible, Ascii, Compact, Renderer, Unicode};
+use singlenum::enums::PairKind;
+
+fn table() -> Table {
+    let mut configuration = [0; 81].to_vec();
+    configuration[0] = 5;
+    configuration[80] = 7;
+    Table::new(configuration, 1).unwrap()
+}
+
+#[test]
+fn test_01_render_ascii() {
+    let mut table = table();
+    table
+        .add_constraint(Constraint::Pair(
+            Pair::new(PairKind::WHITE, [1, 2].to_vec()).unwrap(),
+        ))
+        .unwrap();
+
+    let ascii = Ascii.render(&table);
+    assert!(ascii.is_ascii());
+    assert_eq!(ascii.lines().count(), 19);
+    assert!(ascii.starts_with("+===+===+"));
+    assert!(ascii.contains("| 5 |   o   |"));
+    assert_eq!(
+        ascii.lines().count(),
+        Unicode { color: false }.render(&table).lines().count()
+    );
+}
+
+#[test]
+fn test_01_render_compact() {
+    let compact = Compact.render(&table());
+    let lines: Vec<&str> = compact.lines().collect();
+    assert_eq!(lines.len(), 9);
+    assert_eq!(lines[0], "5........");
+    assert_eq!(lines[8], "........7");
+}
+
+#[test]
+fn test_01_render_accessible() {
+    let mut table = table();
+    table
+        .add_constraint(Constraint::Cage(Cage::new(10, [9, 10].to_vec()).unwrap()))
+        .unwrap();
+
+    let accessible = Accessible.render(&table);
+    let lines: Vec<&str> = accessible.lines().collect();
+    assert_eq!(
+        lines[0],
+        "Row 1: 5, empty, empty, empty, empty, empty, empty, empty, empty."
+    );
+    assert_eq!(
+        lines[9],
+        "Cage summing to 10: row 2 column 1, row 2 column 2."
+    );
+}
+
+#[test]
+fn test_01_render_candidates() {
+    let mut table = table();
+    table.update().unwrap();
+
+    let ascii = Ascii.render_candidates(&table);
+    assert!(ascii.is_ascii());
+    assert_eq!(
+        ascii.lines().count(),
+        Unicode { color: false }
+            .render_candidates(&table)
+            .lines()
+            .count()
+    );
+
+    let compact = Compact.render_candidates(&table);
+    assert!(compact.starts_with("5 [12346789] [12346789] [12346789]"));
+    assert!(compact.lines().nth(8).unwrap().ends_with(" 7"));
+
+    let accessible = Accessible.render_candidates(&table);
+    assert!(accessible.starts_with("Row 1: 5, candidates 1 2 3 4 6 7 8 9, "));
+}
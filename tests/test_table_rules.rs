@@ -0,0 +1,93 @@
+use singlenum::components::constraint::Constraint;
+use singlenum::components::table::core::Table;
+use singlenum::components::table::rules::{neighbours, Elimination};
+use singlenum::enums::{Rule, Step};
+
+/*
+ * ╔═══╤═══╤═══╦═══╤═══╤═══╦═══╤═══╤═══╗
+ * ║ 3 │   │ 5 ║ 8 │   │   ║ 2 │ 4 │   ║
+ * ╟───┼───┼───╫───┼───┼───╫───┼───┼───╢
+ * ║   │   │   ║   │ 4 │ 6 ║ 5 │   │   ║
+ * ╟───┼───┼───╫───┼───┼───╫───┼───┼───╢
+ * ║   │ 4 │   ║   │   │   ║ 8 │ 7 │   ║
+ * ╠═══╪═══╪═══╬═══╪═══╪═══╬═══╪═══╪═══╣
+ * ...
+ */
+fn anti_knight_configuration() -> Vec<usize> {
+    [
+        3, 0, 5, 8, 0, 0, 2, 4, 0, 0, 0, 0, 0, 4, 6, 5, 0, 0, 0, 4, 0, 0, 0, 0, 8, 7, 0, 5, 0, 0,
+        0, 3, 0, 0, 2, 0, 2, 0, 6, 0, 7, 0, 0, 0, 8, 0, 0, 0, 2, 0, 0, 1, 0, 3, 0, 0, 2, 0, 8, 3,
+        0, 0, 0, 6, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 3, 5, 6, 0, 7, 0, 0,
+    ]
+    .to_vec()
+}
+
+#[test]
+fn test_01_neighbours() {
+    let mut knight = neighbours(&Rule::ANTIKNIGHT, 0);
+    knight.sort();
+    assert_eq!(knight, [11, 19].to_vec());
+
+    let mut king = neighbours(&Rule::ANTIKING, 40);
+    king.sort();
+    assert_eq!(king, [30, 31, 32, 39, 41, 48, 49, 50].to_vec());
+}
+
+#[test]
+fn test_01_rule_from_str() {
+    assert_eq!("anti-knight".parse::<Rule>().unwrap(), Rule::ANTIKNIGHT);
+    assert_eq!("anti-king".parse::<Rule>().unwrap(), Rule::ANTIKING);
+    assert!("anti-queen".parse::<Rule>().is_err());
+}
+
+#[test]
+fn test_01_update_anti_knight_potentials() {
+    let mut table = Table::new(anti_knight_configuration(), 1).unwrap();
+    table
+        .add_constraint(Constraint::Rule(Rule::ANTIKNIGHT))
+        .unwrap();
+    table.update().unwrap();
+
+    for square in table.squares.iter().filter(|x| x.value == 0) {
+        for neighbour_id in neighbours(&Rule::ANTIKNIGHT, square.id) {
+            let value = table.squares[neighbour_id].value;
+            assert!(!square.potentials.contains(&value));
+        }
+    }
+}
+
+/*
+ * Every square set under anti-knight comes with the potentials it removes from
+ * the empty squares a knight's move away.
+ */
+#[test]
+fn test_01_anti_knight_eliminations() {
+    let mut table = Table::new(anti_knight_configuration(), 500).unwrap();
+    table
+        .add_constraint(Constraint::Rule(Rule::ANTIKNIGHT))
+        .unwrap();
+
+    let mut eliminations = 0;
+    while !matches!(table.step().unwrap(), Step::Done(_)) {
+        for elimination in table.get_eliminations() {
+            let seen_by = &table.squares[elimination.seen_by];
+            assert_eq!(seen_by.value, elimination.value);
+            assert_eq!(table.squares[elimination.square_id].value, 0);
+            assert!(neighbours(&Rule::ANTIKNIGHT, seen_by.id).contains(&elimination.square_id));
+            eliminations += 1;
+        }
+    }
+    assert!(eliminations > 0);
+    assert!(table.get_eliminations().is_empty());
+
+    let elimination = Elimination {
+        rule: Rule::ANTIKNIGHT,
+        square_id: 11,
+        value: 3,
+        seen_by: 0,
+    };
+    assert_eq!(
+        elimination.to_string(),
+        "anti-knight removes 3 from line 2, column 3, seen from line 1, column 1"
+    );
+}
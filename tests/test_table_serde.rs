@@ -0,0 +1,43 @@
+use singlenum::components::cage::Cage;
+use singlenum::components::constraint::Constraint;
+use singlenum::components::table::core::Table;
+use singlenum::enums::{Progress, Step};
+
+#[test]
+fn test_01_serde_mid_solve() {
+    // Needs a guess, stop right after the first one
+    let configuration: Vec<usize> = [
+        0, 0, 0, 8, 9, 0, 0, 0, 1, 5, 0, 7, 3, 0, 0, 0, 4, 0, 1, 9, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0,
+        0, 0, 5, 0, 1, 9, 9, 0, 5, 0, 0, 0, 6, 0, 2, 2, 1, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+        0, 3, 7, 0, 5, 0, 0, 0, 6, 8, 0, 4, 7, 0, 0, 0, 3, 8, 0, 0, 0,
+    ]
+    .to_vec();
+    let mut table = Table::new(configuration, 500).unwrap();
+    table
+        .add_constraint(Constraint::Cage(Cage::new(10, [0, 1].to_vec()).unwrap()))
+        .unwrap();
+    loop {
+        if let Step::Guessed = table.step().unwrap() {
+            break;
+        }
+    }
+    assert_eq!(table.get_snapshots_taken(), 1);
+
+    let json = serde_json::to_string(&table).unwrap();
+    let mut loaded: Table = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, table);
+    assert_eq!(loaded.get_cages(), table.get_cages());
+
+    // The loaded table carries on from where it was saved
+    assert!(matches!(loaded.solve().unwrap(), Progress::Solved(_)));
+    assert!(loaded.get_snapshots_taken() >= 1);
+}
+
+#[test]
+fn test_01_serde_square() {
+    let table = Table::new([0; 81].to_vec(), 1).unwrap();
+    let json = serde_json::to_value(&table.squares[10]).unwrap();
+    assert_eq!(json["id"], 10);
+    assert_eq!(json["line_ids"], serde_json::json!([1]));
+    assert_eq!(json["kind"], serde_json::Value::Null);
+}
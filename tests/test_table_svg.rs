@@ -0,0 +1,49 @@
+use singlenum::components::cage::Cage;
+use singlenum::components::constraint::Constraint;
+use singlenum::components::pair::Pair;
+use singlenum::components::table::core::Table;
+use singlenum::components::table::svg::render_svg;
+use singlenum::enums::PairKind;
+
+#[test]
+fn test_01_render_svg() {
+    let mut configuration = [0; 81].to_vec();
+    configuration[0] = 5;
+    configuration[80] = 7;
+
+    let table = Table::new(configuration, 1).unwrap();
+    let svg = render_svg(&table, false);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    assert_eq!(svg.matches("font-weight=\"bold\"").count(), 2);
+}
+
+#[test]
+fn test_01_render_svg_candidates() {
+    let mut table = Table::new([0; 81].to_vec(), 1).unwrap();
+    table.update().unwrap();
+
+    // Nine small digits in each square
+    assert!(!render_svg(&table, false).contains("font-size=\"11\""));
+    assert_eq!(
+        render_svg(&table, true).matches("font-size=\"11\"").count(),
+        81 * 9
+    );
+}
+
+#[test]
+fn test_01_render_svg_variants() {
+    let mut table = Table::new([0; 81].to_vec(), 1).unwrap();
+    table
+        .add_constraint(Constraint::Cage(Cage::new(17, [0, 1].to_vec()).unwrap()))
+        .unwrap()
+        .add_constraint(Constraint::Pair(
+            Pair::new(PairKind::BLACK, [40, 41].to_vec()).unwrap(),
+        ))
+        .unwrap();
+
+    let svg = render_svg(&table, false);
+    assert!(svg.contains(">17</text>"));
+    assert!(svg.contains("stroke-dasharray"));
+    assert!(svg.contains("fill=\"black\"/>"));
+}
@@ -0,0 +1,117 @@
+use singlenum::components::constraint::Constraint;
+use singlenum::components::table::core::Table;
+use singlenum::enums::Rule;
+use singlenum::verify;
+use singlenum::verify::{Place, Unit, Violation};
+
+mod common;
+
+use common::PUZZLE;
+
+const SOLUTION: &str =
+    "482753691517896423963124758749612385625938147831475962294381576356247819178569234";
+
+fn squares(line: &str) -> Vec<usize> {
+    line.chars()
+        .map(|x| x.to_digit(10).unwrap_or(0) as usize)
+        .collect()
+}
+
+#[test]
+fn test_01_verify() {
+    let puzzle = Table::new(squares(PUZZLE), 500).unwrap();
+    assert_eq!(verify(&puzzle, &squares(SOLUTION)).unwrap(), []);
+
+    // Errors are not violations
+    assert!(verify(&puzzle, &squares(&SOLUTION[1..])).is_err());
+    let mut invalid = squares(SOLUTION);
+    invalid[0] = 10;
+    assert!(verify(&puzzle, &invalid).is_err());
+}
+
+#[test]
+fn test_01_verify_violations() {
+    let puzzle = Table::new(squares(PUZZLE), 500).unwrap();
+
+    // Square 0 left empty, the given 3 in square 79 swapped with square 80
+    let mut solution = squares(SOLUTION);
+    solution[0] = 0;
+    solution.swap(79, 80);
+    let violations = verify(&puzzle, &solution).unwrap();
+    assert_eq!(
+        violations,
+        [
+            Violation::EMPTY {
+                square_id: 0,
+                line: 0,
+                column: 0,
+                abox: 0
+            },
+            Violation::GIVEN {
+                square_id: 79,
+                line: 8,
+                column: 7,
+                abox: 8,
+                given: 3,
+                value: 4
+            },
+            Violation::DUPLICATE {
+                unit: Unit::COLUMN,
+                id: 7,
+                value: 4,
+                squares: vec![
+                    Place {
+                        square_id: 43,
+                        line: 4,
+                        column: 7
+                    },
+                    Place {
+                        square_id: 79,
+                        line: 8,
+                        column: 7
+                    },
+                ]
+            },
+            Violation::DUPLICATE {
+                unit: Unit::COLUMN,
+                id: 8,
+                value: 3,
+                squares: vec![
+                    Place {
+                        square_id: 17,
+                        line: 1,
+                        column: 8
+                    },
+                    Place {
+                        square_id: 80,
+                        line: 8,
+                        column: 8
+                    },
+                ]
+            },
+        ]
+    );
+    assert_eq!(
+        violations[1].to_string(),
+        "Line 9, column 8 in box 9 is 4, not the given 3"
+    );
+    assert_eq!(
+        violations[2].to_string(),
+        "4 is repeated in column 8: line 5, column 8 and line 9, column 8"
+    );
+}
+
+#[test]
+fn test_01_verify_constraints() {
+    let mut puzzle = Table::new(squares(PUZZLE), 500).unwrap();
+    puzzle
+        .add_constraint(Constraint::Rule(Rule::ANTIKNIGHT))
+        .unwrap();
+    let violations = verify(&puzzle, &squares(SOLUTION)).unwrap();
+    assert_eq!(
+        violations,
+        [Violation::CONSTRAINT {
+            kind: String::from("rules")
+        }]
+    );
+}
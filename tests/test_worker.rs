@@ -0,0 +1,109 @@
+use serde_json::{json, Value};
+use singlenum::serve::Limits;
+use singlenum::worker::{worker, Worker};
+use std::io::Cursor;
+
+mod common;
+
+use common::PUZZLE;
+
+fn call(worker: &mut Worker, id: i32, method: &str, params: Value) -> Value {
+    let request = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+    let response = worker.handle(&request.to_string()).unwrap();
+    assert_eq!(response["id"], id);
+    response
+}
+
+#[test]
+fn test_01_worker_session() {
+    let mut worker = Worker::new(Limits::default());
+
+    let loaded = call(&mut worker, 1, "load", json!({ "puzzle": PUZZLE }));
+    let session = loaded["result"]["session"].as_u64().unwrap();
+    let start = loaded["result"]["grid"].as_str().unwrap().to_string();
+
+    // Steps carry on from the state kept
+    let hint = call(&mut worker, 2, "hint", json!({ "session": session }));
+    let square = hint["result"]["hint"]["square"].as_u64().unwrap() as usize;
+    let candidates = call(&mut worker, 3, "candidates", json!({ "session": session }));
+    let value = hint["result"]["hint"]["value"].clone();
+    assert!(candidates["result"]["candidates"][square]
+        .as_array()
+        .unwrap()
+        .contains(&value));
+
+    let step = call(&mut worker, 4, "step", json!({ "session": session }));
+    assert_eq!(step["result"]["step"], "deduced");
+    assert_eq!(step["result"]["eliminations"], json!([]));
+    let grid = step["result"]["grid"].as_str().unwrap();
+    let set = (0..81).filter(|x| grid[*x..*x + 1] != start[*x..*x + 1]);
+    assert_eq!(set.count(), 1);
+
+    let solved = call(&mut worker, 5, "solve", json!({ "session": session }));
+    assert_eq!(solved["result"]["outcome"], "solved");
+    let step = call(&mut worker, 6, "step", json!({ "session": session }));
+    assert_eq!(step["result"]["step"], "solved");
+    assert_eq!(step["result"]["record"]["grid"], solved["result"]["grid"]);
+
+    let valid = call(&mut worker, 7, "validate", json!({ "session": session }));
+    assert_eq!(valid["result"]["valid"], true);
+
+    call(&mut worker, 8, "close", json!({ "session": session }));
+    let closed = call(&mut worker, 9, "step", json!({ "session": session }));
+    assert_eq!(closed["error"]["code"], -32602);
+}
+
+#[test]
+fn test_01_worker_errors() {
+    let mut worker = Worker::new(Limits::default());
+    let parse = worker.handle("{").unwrap();
+    assert_eq!(parse["error"]["code"], -32700);
+
+    let method = call(&mut worker, 1, "unknown", json!({}));
+    assert_eq!(method["error"]["code"], -32601);
+    let params = call(&mut worker, 2, "solve", json!({ "puzzle": [1, 2] }));
+    assert_eq!(params["error"]["code"], -32602);
+
+    // Conflicting givens are refused, the worker goes on after the solver fails
+    let conflict = format!("11{}", ".".repeat(79));
+    for method in ["load", "solve", "hint", "candidates"] {
+        let refused = call(&mut worker, 3, method, json!({ "puzzle": conflict }));
+        assert_eq!(refused["error"]["message"], "Givens conflict");
+    }
+    let dead = format!("12345678.{}9{}", ".".repeat(8), ".".repeat(63));
+    let loaded = call(&mut worker, 4, "load", json!({ "puzzle": dead }));
+    let session = loaded["result"]["session"].clone();
+    let failed = call(&mut worker, 5, "solve", json!({ "session": session }));
+    assert_eq!(failed["error"]["code"], -32603);
+    assert_eq!(
+        failed["error"]["message"],
+        "No solution, no guess left to roll back"
+    );
+    let closed = call(&mut worker, 6, "step", json!({ "session": session }));
+    assert_eq!(closed["error"]["code"], -32602);
+    let solved = call(&mut worker, 7, "solve", json!({ "puzzle": PUZZLE }));
+    assert_eq!(solved["result"]["outcome"], "solved");
+
+    // Notifications get no response
+    let notification = json!({ "jsonrpc": "2.0", "method": "solve", "params": {} });
+    assert!(worker.handle(&notification.to_string()).is_none());
+}
+
+#[test]
+fn test_01_worker_lines() {
+    let input = format!(
+        "{}\n\n{}\n",
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "load", "params": { "puzzle": PUZZLE } }),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "solve", "params": { "session": 1 } }),
+    );
+    let mut output = Vec::new();
+    worker(Cursor::new(input), &mut output, Limits::default()).unwrap();
+
+    let responses: Vec<Value> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|x| serde_json::from_str(x).unwrap())
+        .collect();
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[1]["result"]["outcome"], "solved");
+}